@@ -0,0 +1,136 @@
+//! This module provides the Chinese (`CNY`) bank holiday calendar.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::Business;
+
+/// `ChinaCalendar` is the bank holiday calendar of mainland China, used for `CNY` settlement.
+///
+/// The public holidays are set by the government every year, extending the festivals into
+/// several days off, which are compensated by designated working days on weekends. Both are
+/// taken from the tables supplied per year: a holiday is a non-business day, while a working
+/// weekend day is a business day.
+///
+/// Note that the exchanges (e.g. the Shanghai Stock Exchange) do not trade on the working
+/// weekend days; these can be modelled by supplying an empty working weekend table.
+#[derive(Debug, Clone, Default)]
+pub struct ChinaCalendar {
+    holidays: HashMap<i32, Vec<NaiveDate>>,
+    working_weekends: HashMap<i32, Vec<NaiveDate>>,
+}
+
+impl ChinaCalendar {
+    /// Creates a calendar using the supplied holidays and working weekend days, keyed by year.
+    #[must_use]
+    pub fn new(
+        holidays: HashMap<i32, Vec<NaiveDate>>,
+        working_weekends: HashMap<i32, Vec<NaiveDate>>,
+    ) -> Self {
+        ChinaCalendar {
+            holidays,
+            working_weekends,
+        }
+    }
+
+    /// Returns the holidays supplied for the given year.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        self.holidays.get(&year).cloned().unwrap_or_default()
+    }
+
+    /// Checks whether the date is a weekend day designated as a working day.
+    #[must_use]
+    pub fn is_working_weekend(&self, day: NaiveDate) -> bool {
+        self.working_weekends
+            .get(&day.year())
+            .is_some_and(|days| days.contains(&day))
+    }
+}
+
+impl Business for ChinaCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays
+            .get(&day.year())
+            .is_some_and(|days| days.contains(&day))
+    }
+
+    fn is_business(&self, day: NaiveDate) -> bool {
+        if self.is_working_weekend(day) {
+            return !self.is_holiday(day);
+        }
+
+        !self.is_weekend(day) && !self.is_holiday(day)
+    }
+
+    /// The make-up working days fall on weekends.
+    fn has_standard_business_days(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// The 2023 Mid-Autumn Festival and National Day holidays, from Friday September 29th to
+    /// Friday October 6th, compensated by working on Saturday October 7th and Sunday 8th.
+    fn golden_week_2023() -> ChinaCalendar {
+        let holidays = date(2023, 9, 29).iter_days().take(8).collect();
+        ChinaCalendar::new(
+            HashMap::from([(2023, holidays)]),
+            HashMap::from([(2023, vec![date(2023, 10, 7), date(2023, 10, 8)])]),
+        )
+    }
+
+    #[test]
+    fn working_saturday_is_business_day() {
+        let calendar = golden_week_2023();
+
+        assert!(calendar.is_working_weekend(date(2023, 10, 7)));
+        assert!(calendar.is_business(date(2023, 10, 7)));
+        assert!(calendar.is_business(date(2023, 10, 8)));
+        assert!(!calendar.is_business(date(2023, 10, 14)));
+    }
+
+    #[test]
+    fn golden_week_holidays() {
+        let calendar = golden_week_2023();
+
+        for day in date(2023, 9, 29).iter_days().take(8) {
+            assert!(!calendar.is_business(day), "{day}");
+        }
+        assert!(calendar.is_business(date(2023, 9, 28)));
+        assert_eq!(
+            calendar.following(date(2023, 9, 29)),
+            Some(date(2023, 10, 7))
+        );
+        assert_eq!(
+            calendar.preceding(date(2023, 10, 6)),
+            Some(date(2023, 9, 28))
+        );
+        assert_eq!(
+            calendar.add_business_days(date(2023, 9, 28), 3),
+            Some(date(2023, 10, 9))
+        );
+    }
+
+    #[test]
+    fn holiday_on_working_weekend_is_not_business_day() {
+        let calendar = ChinaCalendar::new(
+            HashMap::from([(2023, vec![date(2023, 10, 7)])]),
+            HashMap::from([(2023, vec![date(2023, 10, 7)])]),
+        );
+
+        assert!(!calendar.is_business(date(2023, 10, 7)));
+    }
+}
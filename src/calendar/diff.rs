@@ -0,0 +1,86 @@
+//! This module provides the comparison of the holidays of two calendars.
+
+use chrono::NaiveDate;
+
+use crate::calendar::Business;
+
+/// A `CalendarDiff` holds the dates on which two calendars disagree about holidays.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CalendarDiff {
+    /// The dates that are holidays in the first calendar, but not in the second one.
+    pub only_in_a: Vec<NaiveDate>,
+    /// The dates that are holidays in the second calendar, but not in the first one.
+    pub only_in_b: Vec<NaiveDate>,
+}
+
+impl CalendarDiff {
+    /// Checks whether the calendars agree on every date.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty()
+    }
+}
+
+/// Compares the holidays of the calendars `a` and `b` from `start` (inclusive) to `end`
+/// (exclusive), returning the dates on which they disagree in chronological order.
+#[must_use]
+pub fn calendar_diff(
+    a: &dyn Business,
+    b: &dyn Business,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> CalendarDiff {
+    let mut diff = CalendarDiff::default();
+
+    for day in start.iter_days().take_while(|day| *day < end) {
+        match (a.is_holiday(day), b.is_holiday(day)) {
+            (true, false) => diff.only_in_a.push(day),
+            (false, true) => diff.only_in_b.push(day),
+            _ => {}
+        }
+    }
+
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::calendar::set::SetCalendar;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn diff_of_partially_overlapping_calendars() {
+        let a: SetCalendar = [date(2024, 1, 1), date(2024, 3, 29), date(2024, 4, 1)]
+            .into_iter()
+            .collect();
+        let b: SetCalendar = [
+            date(2024, 1, 1),
+            date(2024, 4, 1),
+            date(2024, 5, 1),
+            date(2024, 12, 25),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = calendar_diff(&a, &b, date(2024, 1, 1), date(2025, 1, 1));
+
+        assert_eq!(diff.only_in_a, vec![date(2024, 3, 29)]);
+        assert_eq!(diff.only_in_b, vec![date(2024, 5, 1), date(2024, 12, 25)]);
+        assert!(!diff.is_empty());
+    }
+
+    #[test]
+    fn diff_respects_range() {
+        let a: SetCalendar = [date(2024, 1, 1), date(2024, 3, 29)].into_iter().collect();
+        let b: SetCalendar = [date(2024, 1, 1)].into_iter().collect();
+
+        assert!(calendar_diff(&a, &b, date(2024, 1, 1), date(2024, 3, 29)).is_empty());
+        assert!(calendar_diff(&a, &a, date(2024, 1, 1), date(2025, 1, 1)).is_empty());
+    }
+}
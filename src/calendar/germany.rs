@@ -0,0 +1,72 @@
+//! This module provides the German (Frankfurt) exchange holiday calendar.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::{holidays::easter_offset, Business};
+
+/// `GermanyCalendar` is the exchange holiday calendar of Frankfurt (Xetra), used for `EUR`
+/// instruments settling on German rather than `TARGET` holidays.
+///
+/// The holidays are New Year's Day, Good Friday, Easter Monday, Labour Day, Whit Monday, German
+/// Unity Day (October 3rd), Christmas Eve, Christmas Day, Boxing Day and New Year's Eve.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GermanyCalendar;
+
+impl GermanyCalendar {
+    /// Returns the exchange holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            easter_offset(year, -2),
+            easter_offset(year, 1),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            easter_offset(year, 50),
+            NaiveDate::from_ymd_opt(year, 10, 3),
+            NaiveDate::from_ymd_opt(year, 12, 24),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+            NaiveDate::from_ymd_opt(year, 12, 26),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Business for GermanyCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn german_unity_day() {
+        // Friday, October 3rd, 2025.
+        assert!(GermanyCalendar.is_holiday(date(2025, 10, 3)));
+        assert_eq!(
+            GermanyCalendar.following(date(2025, 10, 3)),
+            Some(date(2025, 10, 6))
+        );
+        assert!(GermanyCalendar.is_business(date(2025, 10, 2)));
+    }
+
+    #[test]
+    fn easter_holidays_2025() {
+        // Easter Sunday was April 20th, 2025.
+        for day in [date(2025, 4, 18), date(2025, 4, 21), date(2025, 6, 9)] {
+            assert!(GermanyCalendar.is_holiday(day), "{day}");
+        }
+        assert!(GermanyCalendar.is_business(date(2025, 4, 17)));
+        assert!(GermanyCalendar.is_business(date(2025, 4, 22)));
+        assert!(GermanyCalendar.is_business(date(2025, 5, 29)));
+    }
+}
@@ -0,0 +1,47 @@
+//! This module provides helper functions used to compute the dates of holidays.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Returns the date of Easter Sunday in the given year of the Gregorian calendar,
+/// computed using the anonymous Gregorian algorithm (Computus).
+///
+/// Returns `None` if the date is out of range.
+#[must_use]
+#[allow(clippy::many_single_char_names)]
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month.try_into().ok()?, day.try_into().ok()?)
+}
+
+/// Returns the date a number of days away from Easter Sunday in the given year, e.g. `-2`
+/// for Good Friday or `1` for Easter Monday.
+///
+/// Returns `None` if the date is out of range.
+#[must_use]
+pub fn easter_offset(year: i32, days: i64) -> Option<NaiveDate> {
+    easter_sunday(year)?.checked_add_signed(Duration::days(days))
+}
+
+/// Returns the first date on or after the given date that falls on the weekday, e.g. Sweden's
+/// Midsummer Eve is the first Friday on or after June 19th.
+///
+/// Returns `None` if the date is out of range.
+#[must_use]
+pub fn weekday_on_or_after(day: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let days = (7 + weekday.num_days_from_monday() - day.weekday().num_days_from_monday()) % 7;
+    day.checked_add_signed(Duration::days(i64::from(days)))
+}
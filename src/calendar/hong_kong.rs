@@ -0,0 +1,160 @@
+//! This module provides the Hong Kong bank holiday calendar.
+
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::calendar::{holidays::easter_offset, Business};
+
+/// `HongKongCalendar` is the bank holiday calendar of Hong Kong, used for `HKD` settlement.
+///
+/// The Gregorian calendar holidays are computed: New Year's Day, Good Friday, the day following
+/// Good Friday, Easter Monday, Labour Day, HKSAR Establishment Day, National Day, Christmas Day
+/// and the first weekday after Christmas Day. A holiday falling on a Sunday is observed on the
+/// next day which is not a holiday.
+///
+/// The holidays following the lunar calendar (the Lunar New Year, Ching Ming Festival, the
+/// Buddha's Birthday, Tuen Ng Festival, the day following the Mid-Autumn Festival and Chung
+/// Yeung Festival) cannot be computed and are taken from a table of their observed Gregorian
+/// dates supplied per year. For years missing from the table, the built-in dates are used
+/// for the years in [`HongKongCalendar::SUPPORTED_YEARS`].
+#[derive(Debug, Clone, Default)]
+pub struct HongKongCalendar {
+    lunar_holidays: HashMap<i32, Vec<NaiveDate>>,
+}
+
+impl HongKongCalendar {
+    /// The years for which the lunar calendar holidays are built-in.
+    pub const SUPPORTED_YEARS: RangeInclusive<i32> = 2023..=2025;
+
+    /// Creates a calendar using the supplied observed dates of the lunar calendar holidays,
+    /// keyed by year.
+    #[must_use]
+    pub fn new(lunar_holidays: HashMap<i32, Vec<NaiveDate>>) -> Self {
+        HongKongCalendar { lunar_holidays }
+    }
+
+    /// Returns the observed dates of the lunar calendar holidays in the given year, either
+    /// supplied or built-in. Returns an empty vector if neither is available.
+    #[must_use]
+    pub fn lunar_holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        if let Some(holidays) = self.lunar_holidays.get(&year) {
+            return holidays.clone();
+        }
+
+        // One year of (month, day) pairs per line.
+        #[rustfmt::skip]
+        let builtin: &[(u32, u32)] = match year {
+            2023 => &[(1, 23), (1, 24), (1, 25), (4, 5), (5, 26), (6, 22), (9, 30), (10, 23)],
+            2024 => &[(2, 10), (2, 12), (2, 13), (4, 4), (5, 15), (6, 10), (9, 18), (10, 11)],
+            2025 => &[(1, 29), (1, 30), (1, 31), (4, 4), (5, 5), (5, 31), (10, 7), (10, 29)],
+            _ => &[],
+        };
+
+        builtin
+            .iter()
+            .filter_map(|(month, day)| NaiveDate::from_ymd_opt(year, *month, *day))
+            .collect()
+    }
+
+    /// Returns the observed bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays = self.lunar_holidays_in_year(year);
+
+        let christmas = NaiveDate::from_ymd_opt(year, 12, 25);
+        let after_christmas = christmas
+            .and_then(|christmas| christmas.succ_opt())
+            .and_then(|day| {
+                if day.weekday() == Weekday::Sun {
+                    day.succ_opt()
+                } else {
+                    Some(day)
+                }
+            });
+        let easter = [
+            easter_offset(year, -2),
+            easter_offset(year, -1),
+            easter_offset(year, 1),
+        ];
+        holidays.extend(easter.into_iter().chain([after_christmas]).flatten());
+
+        let fixed = [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            NaiveDate::from_ymd_opt(year, 7, 1),
+            NaiveDate::from_ymd_opt(year, 10, 1),
+            christmas,
+        ];
+        for mut day in fixed.into_iter().flatten() {
+            if day.weekday() == Weekday::Sun {
+                while holidays.contains(&day) || day.weekday() == Weekday::Sun {
+                    match day.succ_opt() {
+                        Some(next) => day = next,
+                        None => break,
+                    }
+                }
+            }
+            holidays.push(day);
+        }
+
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+}
+
+impl Business for HongKongCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn lunar_new_year_2024() {
+        let calendar = HongKongCalendar::default();
+
+        // The first day, February 10th, is a Saturday and the second is a Sunday, so the
+        // third and fourth days are observed on February 12th and 13th.
+        assert!(calendar.is_holiday(date(2024, 2, 10)));
+        assert!(calendar.is_holiday(date(2024, 2, 12)));
+        assert!(calendar.is_holiday(date(2024, 2, 13)));
+        assert!(!calendar.is_holiday(date(2024, 2, 9)));
+        assert!(!calendar.is_holiday(date(2024, 2, 14)));
+        assert_eq!(
+            calendar.following(date(2024, 2, 10)),
+            Some(date(2024, 2, 14))
+        );
+    }
+
+    #[test]
+    fn supplied_lunar_holidays_take_precedence() {
+        let supplied = vec![date(2024, 2, 14)];
+        let calendar = HongKongCalendar::new(HashMap::from([(2024, supplied.clone())]));
+
+        assert_eq!(calendar.lunar_holidays_in_year(2024), supplied);
+        assert!(calendar.is_holiday(date(2024, 2, 14)));
+        assert!(!calendar.is_holiday(date(2024, 2, 12)));
+    }
+
+    #[test]
+    fn unsupported_year_has_no_lunar_holidays() {
+        let calendar = HongKongCalendar::default();
+
+        assert!(!HongKongCalendar::SUPPORTED_YEARS.contains(&2030));
+        assert!(calendar.lunar_holidays_in_year(2030).is_empty());
+        assert!(calendar.is_holiday(date(2030, 1, 1)));
+    }
+}
@@ -0,0 +1,111 @@
+//! This module provides functions for the `IMM` dates, i.e. the third Wednesdays of March,
+//! June, September and December, used as the standard dates of futures and derivatives.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+/// The months in which the `IMM` dates fall.
+const IMM_MONTHS: [u32; 4] = [3, 6, 9, 12];
+
+/// Returns the `IMM` date in the given year and month, i.e. the third Wednesday of the month.
+///
+/// Returns `None` if the month is not an `IMM` month or the date is out of range.
+#[must_use]
+pub fn imm_date(year: i32, month: u32) -> Option<NaiveDate> {
+    if !IMM_MONTHS.contains(&month) {
+        return None;
+    }
+
+    NaiveDate::from_weekday_of_month_opt(year, month, Weekday::Wed, 3)
+}
+
+/// Checks whether the date is an `IMM` date.
+#[must_use]
+pub fn is_imm_date(day: NaiveDate) -> bool {
+    imm_date(day.year(), day.month()) == Some(day)
+}
+
+/// Returns the first `IMM` date strictly after the given date.
+///
+/// Returns `None` if the date is out of range.
+#[must_use]
+pub fn next_imm(after: NaiveDate) -> Option<NaiveDate> {
+    IMM_MONTHS
+        .iter()
+        .filter_map(|month| imm_date(after.year(), *month))
+        .find(|imm| *imm > after)
+        .or_else(|| imm_date(after.year().checked_add(1)?, IMM_MONTHS[0]))
+}
+
+/// Returns the last `IMM` date strictly before the given date, e.g. the start of the current
+/// `IMM` period.
+///
+/// Returns `None` if the date is out of range.
+#[must_use]
+pub fn prev_imm(before: NaiveDate) -> Option<NaiveDate> {
+    IMM_MONTHS
+        .iter()
+        .rev()
+        .filter_map(|month| imm_date(before.year(), *month))
+        .find(|imm| *imm < before)
+        .or_else(|| imm_date(before.year().checked_sub(1)?, IMM_MONTHS[3]))
+}
+
+/// Returns the first `count` `IMM` dates strictly after the given date, e.g. the pillar dates
+/// of a strip of futures.
+///
+/// The strip stops early if a date is out of range.
+#[must_use]
+pub fn imm_strip(after: NaiveDate, count: usize) -> Vec<NaiveDate> {
+    std::iter::successors(next_imm(after), |imm| next_imm(*imm))
+        .take(count)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn prev_imm_wraps_into_prior_year() {
+        assert_eq!(prev_imm(date(2024, 1, 10)), Some(date(2023, 12, 20)));
+        assert_eq!(prev_imm(date(2024, 3, 20)), Some(date(2023, 12, 20)));
+    }
+
+    #[test]
+    fn prev_imm_just_after_march() {
+        assert_eq!(prev_imm(date(2024, 3, 21)), Some(date(2024, 3, 20)));
+        assert_eq!(prev_imm(date(2024, 6, 19)), Some(date(2024, 3, 20)));
+        assert_eq!(prev_imm(date(2024, 12, 31)), Some(date(2024, 12, 18)));
+        assert_eq!(prev_imm(NaiveDate::MIN), None);
+    }
+
+    #[test]
+    fn imm_strip_of_eight_futures() {
+        let strip = imm_strip(date(2024, 3, 20), 8);
+        assert_eq!(
+            strip,
+            vec![
+                date(2024, 6, 19),
+                date(2024, 9, 18),
+                date(2024, 12, 18),
+                date(2025, 3, 19),
+                date(2025, 6, 18),
+                date(2025, 9, 17),
+                date(2025, 12, 17),
+                date(2026, 3, 18),
+            ]
+        );
+        assert!(strip.iter().all(|imm| is_imm_date(*imm)));
+    }
+
+    #[test]
+    fn imm_strip_stops_at_end_of_range() {
+        let last = prev_imm(NaiveDate::MAX).unwrap();
+        assert_eq!(imm_strip(prev_imm(last).unwrap(), 8), vec![last]);
+        assert!(imm_strip(date(2024, 1, 1), 0).is_empty());
+    }
+}
@@ -0,0 +1,110 @@
+//! This module provides the Indian (`INR`, Mumbai) bank holiday calendar.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::Business;
+
+/// `IndiaCalendar` is the bank holiday calendar of Mumbai, used for `INR` settlement.
+///
+/// Most of the Indian holidays follow the Hindu, Islamic and other religious calendars and are
+/// notified by the government every year, so they are taken from the tables supplied per year
+/// (e.g. Diwali, Holi or Eid). The fixed national holidays - Republic Day (January 26th),
+/// Independence Day (August 15th) and Gandhi Jayanti (October 2nd) - are added automatically.
+#[derive(Debug, Clone, Default)]
+pub struct IndiaCalendar {
+    holidays: HashMap<i32, Vec<NaiveDate>>,
+}
+
+impl IndiaCalendar {
+    /// Creates a calendar using the supplied holidays, keyed by year, in addition to the
+    /// fixed national holidays.
+    #[must_use]
+    pub fn new(holidays: HashMap<i32, Vec<NaiveDate>>) -> Self {
+        IndiaCalendar { holidays }
+    }
+
+    /// Returns the fixed national holidays and the holidays supplied for the given year.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = [
+            // Republic Day
+            NaiveDate::from_ymd_opt(year, 1, 26),
+            // Independence Day
+            NaiveDate::from_ymd_opt(year, 8, 15),
+            // Gandhi Jayanti
+            NaiveDate::from_ymd_opt(year, 10, 2),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if let Some(supplied) = self.holidays.get(&year) {
+            for day in supplied {
+                if !holidays.contains(day) {
+                    holidays.push(*day);
+                }
+            }
+        }
+        holidays.sort_unstable();
+
+        holidays
+    }
+}
+
+impl Business for IndiaCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        matches!((day.month(), day.day()), (1, 26) | (8, 15) | (10, 2))
+            || self
+                .holidays
+                .get(&day.year())
+                .is_some_and(|days| days.contains(&day))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn fixed_national_holidays() {
+        let calendar = IndiaCalendar::default();
+
+        for year in [2023, 2024, 2025] {
+            for day in [date(year, 1, 26), date(year, 8, 15), date(year, 10, 2)] {
+                assert!(calendar.is_holiday(day), "{day}");
+            }
+        }
+        assert!(!calendar.is_holiday(date(2024, 1, 25)));
+        assert_eq!(
+            calendar.holidays_in_year(2024),
+            [date(2024, 1, 26), date(2024, 8, 15), date(2024, 10, 2)]
+        );
+    }
+
+    #[test]
+    fn supplied_diwali() {
+        // Diwali (Lakshmi Puja) fell on Friday, November 1st, 2024.
+        let diwali = date(2024, 11, 1);
+        let calendar = IndiaCalendar::new(HashMap::from([(2024, vec![diwali, date(2024, 8, 15)])]));
+
+        assert!(calendar.is_holiday(diwali));
+        assert!(!calendar.is_business(diwali));
+        assert!(!calendar.is_holiday(date(2023, 11, 1)));
+        assert_eq!(calendar.following(diwali), Some(date(2024, 11, 4)));
+        assert_eq!(
+            calendar.holidays_in_year(2024),
+            [
+                date(2024, 1, 26),
+                date(2024, 8, 15),
+                date(2024, 10, 2),
+                diwali
+            ]
+        );
+    }
+}
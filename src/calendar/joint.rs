@@ -0,0 +1,171 @@
+//! This module provides the joint calendar, combining the holidays of several calendars.
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::calendar::Business;
+
+/// `JointCalendar` is a calendar in which a day is a business day only if it is a business day
+/// in every one of the combined calendars, e.g. for a cross-currency payment settled in both
+/// currencies.
+///
+/// A joint calendar of no calendars has neither holidays nor weekends.
+#[derive(Default)]
+pub struct JointCalendar<'a> {
+    calendars: Vec<Box<dyn Business + 'a>>,
+    weekend: Vec<Weekday>,
+}
+
+impl<'a> JointCalendar<'a> {
+    /// Creates a calendar combining the `calendars`.
+    #[must_use]
+    pub fn new(calendars: Vec<Box<dyn Business + 'a>>) -> Self {
+        let mut joint = JointCalendar::default();
+        for calendar in calendars {
+            joint.add(calendar);
+        }
+        joint
+    }
+
+    /// Creates a calendar combining the borrowed `calendars`, e.g.
+    /// `JointCalendar::from_slice(&[&TargetCalendar, &UnitedStatesCalendar])`.
+    #[must_use]
+    pub fn from_slice(calendars: &[&'a dyn Business]) -> Self {
+        JointCalendar::new(
+            calendars
+                .iter()
+                .map(|calendar| Box::new(*calendar) as Box<dyn Business + 'a>)
+                .collect(),
+        )
+    }
+
+    /// Adds the `calendar` to the combined calendars.
+    pub fn add(&mut self, calendar: Box<dyn Business + 'a>) {
+        for day in calendar.weekend_days() {
+            if !self.weekend.contains(day) {
+                self.weekend.push(*day);
+            }
+        }
+        self.calendars.push(calendar);
+    }
+
+    /// Returns the combined calendars.
+    #[must_use]
+    pub fn calendars(&self) -> &[Box<dyn Business + 'a>] {
+        &self.calendars
+    }
+}
+
+impl Business for JointCalendar<'_> {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.calendars
+            .iter()
+            .any(|calendar| calendar.is_holiday(day))
+    }
+
+    /// Returns the days of the week forming the weekend of any of the combined calendars.
+    fn weekend_days(&self) -> &[Weekday] {
+        &self.weekend
+    }
+
+    fn is_weekend(&self, day: NaiveDate) -> bool {
+        self.calendars
+            .iter()
+            .any(|calendar| calendar.is_weekend(day))
+    }
+
+    fn is_business(&self, day: NaiveDate) -> bool {
+        self.calendars
+            .iter()
+            .all(|calendar| calendar.is_business(day))
+    }
+
+    fn has_standard_business_days(&self) -> bool {
+        self.calendars
+            .iter()
+            .all(|calendar| calendar.has_standard_business_days())
+    }
+}
+
+/// Creates a [`JointCalendar`] combining the calendars supplied, each of which is boxed, e.g.
+/// `joint![&us, &target]` or `joint![TargetCalendar, WeekendCalendar]`.
+///
+/// [`JointCalendar`]: crate::calendar::joint::JointCalendar
+#[macro_export]
+macro_rules! joint {
+    ($($calendar:expr),* $(,)?) => {
+        $crate::calendar::joint::JointCalendar::new(vec![
+            $(Box::new($calendar) as Box<dyn $crate::calendar::Business + '_>),*
+        ])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::{
+        switzerland::SwitzerlandCalendar, target::TargetCalendar,
+        united_states::UnitedStatesCalendar,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// Asserts that a date which is a holiday in any one of the US, Swiss and `TARGET`
+    /// calendars is not a business day in the joint calendar.
+    fn assert_three_way(joint: &JointCalendar<'_>) {
+        let us = UnitedStatesCalendar;
+        let swiss = SwitzerlandCalendar;
+        let holidays = [
+            // Independence Day, a US holiday only.
+            (date(2024, 7, 4), &us as &dyn Business),
+            // Thanksgiving Day, a US holiday only.
+            (date(2024, 11, 28), &us),
+            // Berchtold's Day, a Swiss holiday only.
+            (date(2024, 1, 2), &swiss),
+            // Swiss National Day, a Swiss holiday only.
+            (date(2024, 8, 1), &swiss),
+            // Labour Day, a TARGET and Swiss holiday, but not a US holiday.
+            (date(2024, 5, 1), &TargetCalendar),
+        ];
+
+        assert_eq!(joint.calendars().len(), 3);
+        for (day, calendar) in holidays {
+            assert!(calendar.is_holiday(day), "{day}");
+            assert!(joint.is_holiday(day), "{day}");
+            assert!(!joint.is_business(day), "{day}");
+        }
+        assert!(joint.is_business(date(2024, 7, 3)));
+        assert!(!joint.is_business(date(2024, 7, 6)));
+        assert_eq!(joint.following(date(2024, 7, 4)), Some(date(2024, 7, 5)));
+    }
+
+    #[test]
+    fn from_slice_three_way() {
+        let (us, swiss) = (UnitedStatesCalendar, SwitzerlandCalendar);
+        assert_three_way(&JointCalendar::from_slice(&[&us, &swiss, &TargetCalendar]));
+    }
+
+    #[test]
+    fn macro_three_way() {
+        let us = UnitedStatesCalendar;
+        assert_three_way(&crate::joint![&us, SwitzerlandCalendar, TargetCalendar]);
+    }
+
+    #[test]
+    fn add_three_way() {
+        let mut joint = JointCalendar::default();
+        joint.add(Box::new(UnitedStatesCalendar));
+        joint.add(Box::new(SwitzerlandCalendar));
+        joint.add(Box::new(TargetCalendar));
+        assert_three_way(&joint);
+        assert_eq!(joint.weekend_days(), UnitedStatesCalendar.weekend_days());
+    }
+
+    #[test]
+    fn empty_joint_calendar_has_no_holidays_or_weekends() {
+        let joint = JointCalendar::default();
+        assert!(joint.is_business(date(2024, 7, 6)));
+        assert!(joint.weekend_days().is_empty());
+    }
+}
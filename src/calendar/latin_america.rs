@@ -0,0 +1,153 @@
+//! This module provides the Mexican and Brazilian bank holiday calendars.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::calendar::{holidays::easter_offset, Business};
+
+/// `MexicoCalendar` is the bank holiday calendar of Mexico City, used for `MXN` settlement.
+///
+/// The holidays are New Year's Day, Constitution Day (the first Monday of February), Benito
+/// Juárez's Birthday (the third Monday of March), Holy Thursday, Good Friday, Labour Day,
+/// Independence Day (September 16th), All Souls' Day, Revolution Day (the third Monday of
+/// November), Our Lady of Guadalupe (December 12th) and Christmas Day. The "puente" holidays are
+/// observed on Mondays for every year, following the rule in force since 2006.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MexicoCalendar;
+
+impl MexicoCalendar {
+    /// Returns the bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_weekday_of_month_opt(year, 2, Weekday::Mon, 1),
+            NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Mon, 3),
+            easter_offset(year, -3),
+            easter_offset(year, -2),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            NaiveDate::from_ymd_opt(year, 9, 16),
+            NaiveDate::from_ymd_opt(year, 11, 2),
+            NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Mon, 3),
+            NaiveDate::from_ymd_opt(year, 12, 12),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+}
+
+impl Business for MexicoCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+/// `BrazilCalendar` is the national bank holiday calendar of Brazil (`ANBIMA`), used for `BRL`
+/// settlement and the `BUS/252` day count convention.
+///
+/// The holidays are New Year's Day, Carnival Monday and Tuesday (48 and 47 days before Easter),
+/// Good Friday, Tiradentes Day (April 21st), Labour Day, Corpus Christi, Independence Day
+/// (September 7th), Our Lady of Aparecida (October 12th), All Souls' Day, Republic Day (November
+/// 15th), Black Consciousness Day (November 20th, since 2024) and Christmas Day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BrazilCalendar;
+
+impl BrazilCalendar {
+    /// Returns the bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let black_consciousness = if year >= 2024 {
+            NaiveDate::from_ymd_opt(year, 11, 20)
+        } else {
+            None
+        };
+
+        let mut holidays: Vec<NaiveDate> = [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            easter_offset(year, -48),
+            easter_offset(year, -47),
+            easter_offset(year, -2),
+            NaiveDate::from_ymd_opt(year, 4, 21),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            easter_offset(year, 60),
+            NaiveDate::from_ymd_opt(year, 9, 7),
+            NaiveDate::from_ymd_opt(year, 10, 12),
+            NaiveDate::from_ymd_opt(year, 11, 2),
+            NaiveDate::from_ymd_opt(year, 11, 15),
+            black_consciousness,
+            NaiveDate::from_ymd_opt(year, 12, 25),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+}
+
+impl Business for BrazilCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn brazil_carnival_2024() {
+        // Easter Sunday was March 31st, 2024, so Carnival fell on February 12th and 13th.
+        assert!(BrazilCalendar.is_holiday(date(2024, 2, 12)));
+        assert!(BrazilCalendar.is_holiday(date(2024, 2, 13)));
+        assert!(BrazilCalendar.is_business(date(2024, 2, 9)));
+        assert!(BrazilCalendar.is_business(date(2024, 2, 14)));
+        assert_eq!(
+            BrazilCalendar.following(date(2024, 2, 10)),
+            Some(date(2024, 2, 14))
+        );
+    }
+
+    #[test]
+    fn brazil_holidays_2024() {
+        assert_eq!(
+            BrazilCalendar.holidays_in_year(2024),
+            [
+                date(2024, 1, 1),
+                date(2024, 2, 12),
+                date(2024, 2, 13),
+                date(2024, 3, 29),
+                date(2024, 4, 21),
+                date(2024, 5, 1),
+                date(2024, 5, 30),
+                date(2024, 9, 7),
+                date(2024, 10, 12),
+                date(2024, 11, 2),
+                date(2024, 11, 15),
+                date(2024, 11, 20),
+                date(2024, 12, 25),
+            ]
+        );
+        assert!(!BrazilCalendar.is_holiday(date(2023, 11, 20)));
+    }
+
+    #[test]
+    fn mexico_puente_mondays_2024() {
+        for day in [date(2024, 2, 5), date(2024, 3, 18), date(2024, 11, 18)] {
+            assert_eq!(day.weekday(), Weekday::Mon, "{day}");
+            assert!(MexicoCalendar.is_holiday(day), "{day}");
+        }
+        assert!(!MexicoCalendar.is_holiday(date(2024, 2, 6)));
+        assert!(MexicoCalendar.is_holiday(date(2024, 3, 28)));
+        assert!(MexicoCalendar.is_holiday(date(2024, 12, 12)));
+    }
+}
@@ -1,29 +1,106 @@
 //! The `calendar` module provides types and methods for income and settlement dates
 //! adjustment.
 
-use chrono::{Datelike, Duration, NaiveDate, Weekday};
+pub mod china;
+pub mod diff;
+pub mod germany;
+pub mod holidays;
+pub mod hong_kong;
+pub mod imm;
+pub mod india;
+pub mod joint;
+pub mod latin_america;
+pub mod nordic;
+pub mod overlay;
+pub mod precomputed;
+pub mod set;
+#[cfg(feature = "serde")]
+pub mod spec;
+pub mod switzerland;
+pub mod target;
+pub mod united_states;
+pub mod validate;
+pub mod weekend;
+
+use std::{error::Error, fmt};
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use rust_decimal::Decimal;
+
+use crate::{
+    fixed_income::day_count_fraction::{bus_252, DayCountConvention},
+    tenor::{add_months, is_end_of_month, last_day_of_month, Tenor},
+    AccrualError, BusinessDayConvetion,
+};
+
+/// The default weekend, see [`Business::weekend_days`].
+const SATURDAY_SUNDAY: [Weekday; 2] = [Weekday::Sat, Weekday::Sun];
 
 /// `BusinessCalendar` trait allows implementation of bank holiday calendars,
 /// which are used for accrual calculation date rolling.
 ///
 /// The trait is built upon [`chrono::NaiveDate`] type - as such it follows
 /// the `ISO 8601` standard.
+///
+/// The default date adjustment methods step one day at a time and check every day with
+/// [`Business::is_business`], so that calendars overriding [`Business::is_weekend`] or
+/// [`Business::is_business`] (e.g. with weekend days designated as working days) are respected.
+/// With a Saturday and Sunday weekend and standard business days (see
+/// [`Business::has_standard_business_days`]), [`Business::following`] and
+/// [`Business::preceding`] jump over the weekends instead.
+///
+/// The trait is dyn compatible, so that calendars can be combined and selected at runtime as
+/// `&dyn Business` or `Box<dyn Business>`. Methods which can not be called on a trait object,
+/// e.g. returning `impl Iterator`, must be bound by `where Self: Sized`.
 pub trait Business {
     /// Checks whether the date is a bank holiday.
     fn is_holiday(&self, day: NaiveDate) -> bool;
 
-    /// Checks whether the date falls on a weekend.
+    /// Returns the days of the week forming the weekend.
     /// Default implementation assumes that weekend consists of Saturday and Sunday,
-    /// which is not true for all countries.
+    /// which is not true for all countries (e.g. Friday and Saturday in some Middle
+    /// Eastern countries).
+    fn weekend_days(&self) -> &[Weekday] {
+        &SATURDAY_SUNDAY
+    }
+
+    /// Checks whether the date falls on a weekend, i.e. on one of the
+    /// [`Business::weekend_days`]. Every date adjustment method respects the overridden
+    /// weekend, as it checks the days with [`Business::is_business`].
     fn is_weekend(&self, day: NaiveDate) -> bool {
-        !(day.weekday() == Weekday::Sat) && !(day.weekday() == Weekday::Sun)
+        self.weekend_days().contains(&day.weekday())
     }
 
     /// Checks whether the date is a business day.
     /// By default, it is assumed that if the day does not fall
     /// on weekend and is not a bank holiday, then it's a business day.
+    /// The weekend is checked first, so that the usually more expensive holiday
+    /// lookup is skipped for weekend days. This is tested by counting the holiday lookups
+    /// over a week rather than with a criterion benchmark, as criterion is not available to
+    /// this build and the count does not depend on the machine.
     fn is_business(&self, day: NaiveDate) -> bool {
-        !self.is_holiday(day) && !self.is_weekend(day)
+        !self.is_weekend(day) && !self.is_holiday(day)
+    }
+
+    /// Checks whether the date is a business day, an alias of [`Business::is_business`].
+    fn is_business_day(&self, day: NaiveDate) -> bool {
+        self.is_business(day)
+    }
+
+    /// Checks whether the date is not a business day, i.e. the negation of
+    /// [`Business::is_business`].
+    fn is_holiday_or_weekend(&self, day: NaiveDate) -> bool {
+        !self.is_business(day)
+    }
+
+    /// Checks whether the business days are exactly the days which are neither weekend days
+    /// nor holidays, as in the default [`Business::is_business`]. If so, and the weekend is
+    /// Saturday and Sunday, the default counting and searching methods skip the weekends.
+    ///
+    /// Calendars overriding [`Business::is_business`] or [`Business::is_weekend`] with
+    /// exceptions, e.g. weekend days designated as working days, must return `false`.
+    fn has_standard_business_days(&self) -> bool {
+        true
     }
 
     /// Calculates the adjusted date using the `following` convention and returns it in a form of
@@ -33,19 +110,37 @@ pub trait Business {
     /// Otherwise, the adjusted date will be the first business day following the unadjusted date.
     ///
     /// Returns `None` if no such business day exist.
-    fn following(&self, mut day: NaiveDate) -> Option<NaiveDate> {
+    fn following(&self, day: NaiveDate) -> Option<NaiveDate> {
+        self.try_following(day).ok()
+    }
+
+    /// Calculates the adjusted date using the `following` convention. Unlike
+    /// [`Business::following`], a date which is already a business day (returned unchanged)
+    /// is told apart from an exhausted search.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccrualError::CalendarExhausted`] if the search runs past [`NaiveDate::MAX`]
+    /// without finding a business day.
+    fn try_following(&self, mut day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        let from = day;
         if self.is_business(day) {
-            return Some(day);
+            return Ok(day);
         }
 
-        while day < chrono::naive::MAX_DATE {
-            day += Duration::days(1);
+        let skip_weekends = skips_weekends(self);
+        while let Some(next) = next_candidate(day, skip_weekends) {
+            day = next;
             if self.is_business(day) {
-                return Some(day);
+                return Ok(day);
             }
         }
 
-        None
+        Err(CalendarExhausted {
+            from,
+            direction: SearchDirection::Forward,
+        }
+        .into())
     }
 
     /// Calculates the adjusted date using the `modified following` convention and returns it in a form of
@@ -62,16 +157,19 @@ pub trait Business {
             return Some(day);
         }
 
-        while day < chrono::naive::MAX_DATE && day.month() == unadjusted.month() {
-            day += Duration::days(1);
-            if day.month() != unadjusted.month() {
+        while let Some(next) = day.checked_add_days(Days::new(1)) {
+            day = next;
+            if (day.year(), day.month()) != (unadjusted.year(), unadjusted.month()) {
                 break;
             }
+            if self.is_business(day) {
+                return Some(day);
+            }
         }
 
         day = unadjusted;
-        while day > chrono::naive::MIN_DATE {
-            day -= Duration::days(1);
+        while let Some(next) = day.checked_sub_days(Days::new(1)) {
+            day = next;
             if self.is_business(day) {
                 return Some(day);
             }
@@ -80,6 +178,68 @@ pub trait Business {
         None
     }
 
+    /// Calculates the adjusted date using the `modified following` convention, like
+    /// [`Business::modified_following`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccrualError::CalendarExhausted`] if there is no business day later in the
+    /// month and the search backward runs past [`NaiveDate::MIN`].
+    fn try_modified_following(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        self.modified_following(day).ok_or(
+            CalendarExhausted {
+                from: day,
+                direction: SearchDirection::Backward,
+            }
+            .into(),
+        )
+    }
+
+    /// Calculates the adjusted date using the `end of month modified following` convention and
+    /// returns it in a form of `Option<NaiveDate>` enum.
+    ///
+    /// If the supplied date is the last calendar day of its month, the last business day of that
+    /// month is returned (see [`Business::last_business_day_of_month`]), e.g. February 28th of a
+    /// non-leap year falling on a Saturday is adjusted to Friday 27th. Otherwise the date is
+    /// adjusted using the [`Business::modified_following`] convention.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn end_of_month_modified_following(&self, day: NaiveDate) -> Option<NaiveDate> {
+        if is_end_of_month(day) {
+            self.last_business_day_of_month(day.year(), day.month())
+        } else {
+            self.modified_following(day)
+        }
+    }
+
+    /// Returns the business day nearest to the supplied date in a form of `Option<NaiveDate>`
+    /// enum, searching outward in both directions.
+    ///
+    /// If the supplied date is a business date, it is returned with no adjustment. If the nearest
+    /// business days before and after the date are equally far, the following one is returned.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn nearest_business_day(&self, day: NaiveDate) -> Option<NaiveDate> {
+        if self.is_business(day) {
+            return Some(day);
+        }
+
+        let mut after = Some(day);
+        let mut before = Some(day);
+        while after.is_some() || before.is_some() {
+            after = after.and_then(|date| date.succ_opt());
+            if let Some(date) = after.filter(|date| self.is_business(*date)) {
+                return Some(date);
+            }
+            before = before.and_then(|date| date.pred_opt());
+            if let Some(date) = before.filter(|date| self.is_business(*date)) {
+                return Some(date);
+            }
+        }
+
+        None
+    }
+
     /// Calculates the adjusted date using the `preceding` convention and returns it in a form of
     /// `Option<NaiveDate>` enum.
     ///
@@ -87,19 +247,37 @@ pub trait Business {
     /// Otherwise, the adjusted date will be the first business day before the unadjusted date.
     ///
     /// Returns `None` if no such business day exist.
-    fn preceding(&self, mut day: NaiveDate) -> Option<NaiveDate> {
+    fn preceding(&self, day: NaiveDate) -> Option<NaiveDate> {
+        self.try_preceding(day).ok()
+    }
+
+    /// Calculates the adjusted date using the `preceding` convention. Unlike
+    /// [`Business::preceding`], a date which is already a business day (returned unchanged)
+    /// is told apart from an exhausted search.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccrualError::CalendarExhausted`] if the search runs past [`NaiveDate::MIN`]
+    /// without finding a business day.
+    fn try_preceding(&self, mut day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        let from = day;
         if self.is_business(day) {
-            return Some(day);
+            return Ok(day);
         }
 
-        while day < chrono::naive::MIN_DATE {
-            day -= Duration::days(1);
+        let skip_weekends = skips_weekends(self);
+        while let Some(next) = previous_candidate(day, skip_weekends) {
+            day = next;
             if self.is_business(day) {
-                return Some(day);
+                return Ok(day);
             }
         }
 
-        None
+        Err(CalendarExhausted {
+            from,
+            direction: SearchDirection::Backward,
+        }
+        .into())
     }
 
     /// Calculates the adjusted date using the `following` convention and returns it in a form of
@@ -117,8 +295,8 @@ pub trait Business {
             return Some(day);
         }
 
-        while day > chrono::naive::MIN_DATE {
-            day -= Duration::days(1);
+        while let Some(next) = day.checked_sub_days(Days::new(1)) {
+            day = next;
             if day.month() != unadjusted.month() {
                 break;
             }
@@ -128,8 +306,8 @@ pub trait Business {
         }
 
         day = unadjusted;
-        while day < chrono::naive::MAX_DATE {
-            day += Duration::days(1);
+        while let Some(next) = day.checked_add_days(Days::new(1)) {
+            day = next;
             if self.is_business(day) {
                 return Some(day);
             }
@@ -137,8 +315,2195 @@ pub trait Business {
 
         None
     }
+
+    /// Calculates the adjusted date using the `modified preceding` convention, like
+    /// [`Business::modified_preceding`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccrualError::CalendarExhausted`] if there is no business day earlier in the
+    /// month and the search forward runs past [`NaiveDate::MAX`].
+    fn try_modified_preceding(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        self.modified_preceding(day).ok_or(
+            CalendarExhausted {
+                from: day,
+                direction: SearchDirection::Forward,
+            }
+            .into(),
+        )
+    }
+
+    /// Moves the date by a number of business days and returns it in a form of
+    /// `Option<NaiveDate>` enum. A negative number of days moves the date backwards.
+    ///
+    /// The supplied date itself is not counted, e.g. two business days after a Friday is the
+    /// following Tuesday. If `days` is zero, the date is adjusted using the `following`
+    /// convention.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn add_business_days(&self, mut day: NaiveDate, days: i64) -> Option<NaiveDate> {
+        if days == 0 {
+            return self.following(day);
+        }
+
+        let mut remaining = days.unsigned_abs();
+        while remaining > 0 {
+            day = if days > 0 {
+                day.succ_opt()?
+            } else {
+                day.pred_opt()?
+            };
+            if self.is_business(day) {
+                remaining -= 1;
+            }
+        }
+
+        debug_assert!(self.is_business(day), "{day} is not a business day");
+        Some(day)
+    }
+
+    /// Moves the date by a number of business days, like [`Business::add_business_days`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccrualError::CalendarExhausted`] if the date runs past [`NaiveDate::MAX`] (or
+    /// [`NaiveDate::MIN`] for a negative number of days) before moving by all the days.
+    fn try_add_business_days(&self, day: NaiveDate, days: i64) -> Result<NaiveDate, AccrualError> {
+        let direction = if days < 0 {
+            SearchDirection::Backward
+        } else {
+            SearchDirection::Forward
+        };
+
+        self.add_business_days(day, days).ok_or(
+            CalendarExhausted {
+                from: day,
+                direction,
+            }
+            .into(),
+        )
+    }
+
+    /// Counts the business days from `start` (inclusive) to `end` (exclusive).
+    ///
+    /// With a Saturday and Sunday weekend and standard business days (see
+    /// [`Business::has_standard_business_days`]), the weekend days are counted with
+    /// [`weekend_count`] and only the weekdays are checked for holidays. Otherwise every day
+    /// of the range is checked with [`Business::is_business`].
+    ///
+    /// Returns `None` if `start` is after `end`.
+    fn count_business_days(&self, start: NaiveDate, end: NaiveDate) -> Option<u32> {
+        if start > end {
+            return None;
+        }
+
+        if skips_weekends(self) {
+            let holidays = start
+                .iter_days()
+                .take_while(|day| *day < end)
+                .filter(|day| !SATURDAY_SUNDAY.contains(&day.weekday()) && self.is_holiday(*day))
+                .count();
+            let weekdays = (end - start).num_days() - i64::from(weekend_count(start, end));
+
+            return u32::try_from(weekdays - i64::try_from(holidays).ok()?).ok();
+        }
+
+        let count = start
+            .iter_days()
+            .take_while(|day| *day < end)
+            .filter(|day| self.is_business(*day))
+            .count();
+
+        u32::try_from(count).ok()
+    }
+
+    /// Counts the days from `start` (inclusive) to `end` (exclusive) which are not holidays,
+    /// like [`Business::count_business_days`] but counting the weekend days, e.g. for fees
+    /// accruing on weekends but not on bank holidays.
+    ///
+    /// Returns `None` if `start` is after `end`.
+    fn non_holiday_days(&self, start: NaiveDate, end: NaiveDate) -> Option<u32> {
+        if start > end {
+            return None;
+        }
+
+        let count = start
+            .iter_days()
+            .take_while(|day| *day < end)
+            .filter(|day| !self.is_holiday(*day))
+            .count();
+
+        u32::try_from(count).ok()
+    }
+
+    /// Counts the business days from `from` (inclusive) to `to` (exclusive), or from `to`
+    /// (inclusive) to `from` (exclusive) as a negative number if `to` is before `from`.
+    fn business_days_until(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        if to < from {
+            return -self.business_days_until(to, from);
+        }
+
+        // The count of any range within the dates supported by `NaiveDate` fits into `u32`.
+        self.count_business_days(from, to).map_or(0, i64::from)
+    }
+
+    /// Counts the business days of every range, like [`Business::count_business_days`],
+    /// splitting the ranges between as many threads as the available parallelism.
+    ///
+    /// The threads are spawned with [`std::thread::scope`] rather than taken from a rayon
+    /// pool, so the `parallel` feature adds no dependencies. The results are returned in the
+    /// order of the ranges.
+    #[cfg(feature = "parallel")]
+    fn count_business_days_batch(&self, ranges: &[(NaiveDate, NaiveDate)]) -> Vec<Option<u32>>
+    where
+        Self: Sync + Sized,
+    {
+        let threads = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+        let chunk_size = ranges.len().div_ceil(threads).max(1);
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = ranges
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|(start, end)| self.count_business_days(*start, *end))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+
+    /// Summarises the business days from `start` (inclusive) to `end` (exclusive) in a single
+    /// pass, returning their count together with the first and the last of them.
+    ///
+    /// Returns `None` if `start` is after `end`.
+    fn business_days_list_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Option<BusinessDaySummary> {
+        if start > end {
+            return None;
+        }
+
+        let mut summary = BusinessDaySummary::default();
+        for day in start.iter_days().take_while(|day| *day < end) {
+            if self.is_business(day) {
+                summary.count = summary.count.checked_add(1)?;
+                summary.first.get_or_insert(day);
+                summary.last = Some(day);
+            }
+        }
+
+        Some(summary)
+    }
+
+    /// Returns an iterator over the business days from `start` (inclusive) to `end`
+    /// (exclusive), in chronological order.
+    fn business_days(&self, start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate>
+    where
+        Self: Sized,
+    {
+        start
+            .iter_days()
+            .take_while(move |day| *day < end)
+            .filter(move |day| self.is_business(*day))
+    }
+
+    /// Returns an iterator over the business days from `end` (exclusive) down to `start`
+    /// (inclusive), i.e. the days of [`Business::business_days`] in reverse order.
+    fn business_days_rev(&self, start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate>
+    where
+        Self: Sized,
+    {
+        std::iter::successors(end.pred_opt(), NaiveDate::pred_opt)
+            .take_while(move |day| *day >= start)
+            .filter(move |day| self.is_business(*day))
+    }
+
+    /// Returns the first `n` business days strictly after the `from` date, in chronological
+    /// order, e.g. a sequence of reset dates.
+    ///
+    /// Fewer days are returned if the dates run out of the range of [`NaiveDate`].
+    fn next_n_business_days(&self, from: NaiveDate, n: usize) -> Vec<NaiveDate> {
+        std::iter::successors(self.add_business_days(from, 1), |day| {
+            self.add_business_days(*day, 1)
+        })
+        .take(n)
+        .collect()
+    }
+
+    /// Returns every weekend day and holiday from `start` (inclusive) to `end` (exclusive),
+    /// in chronological order.
+    fn non_business_days(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|day| *day < end)
+            .filter(|day| !self.is_business(*day))
+            .collect()
+    }
+
+    /// Returns every holiday from `start` (inclusive) to `end` (exclusive), in chronological
+    /// order, including the holidays falling on a weekend but not the other weekend days.
+    fn holidays_between(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+        start
+            .iter_days()
+            .take_while(|day| *day < end)
+            .filter(|day| self.is_holiday(*day))
+            .collect()
+    }
+
+    /// Returns the day count fraction for the dates provided using the `day_count` convention,
+    /// counting the business days of this calendar for [`DayCountConvention::Business252`].
+    ///
+    /// Returns `None` if `start` is after `end`, or the convention requires the coupon period
+    /// (see [`DayCountConvention::requires_period`]).
+    fn period_year_fraction(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        day_count: &DayCountConvention,
+    ) -> Option<Decimal> {
+        match day_count {
+            DayCountConvention::Business252 => bus_252(start, end, self),
+            _ => day_count.year_fraction(start, end),
+        }
+    }
+
+    /// Calculates the adjusted date using the supplied business day convention and returns it
+    /// in a form of `Option<NaiveDate>` enum.
+    ///
+    /// In debug builds, the adjusted date is checked to be a business day on the right side of
+    /// the unadjusted date, catching calendars whose overridden adjustment methods are
+    /// inconsistent with [`Business::is_business`].
+    ///
+    /// Returns `None` if no such business day exist.
+    fn adjust(&self, day: NaiveDate, convention: &BusinessDayConvetion) -> Option<NaiveDate> {
+        let adjusted = match convention {
+            BusinessDayConvetion::Following => self.following(day),
+            BusinessDayConvetion::ModifiedFollowiing => self.modified_following(day),
+            BusinessDayConvetion::EndOfMonthModifiedFollowing => {
+                self.end_of_month_modified_following(day)
+            }
+            BusinessDayConvetion::Preceding => self.preceding(day),
+            BusinessDayConvetion::ModifiedPreceding => self.modified_preceding(day),
+            BusinessDayConvetion::NoAdjustment => Some(day),
+        };
+
+        if let Some(adjusted) =
+            adjusted.filter(|_| *convention != BusinessDayConvetion::NoAdjustment)
+        {
+            debug_assert!(
+                self.is_business(adjusted),
+                "{day} adjusted to {adjusted}, which is not a business day"
+            );
+            debug_assert!(
+                match convention {
+                    BusinessDayConvetion::Following => adjusted >= day,
+                    BusinessDayConvetion::Preceding => adjusted <= day,
+                    _ => true,
+                },
+                "{day} adjusted to {adjusted} in the wrong direction"
+            );
+        }
+
+        adjusted
+    }
+
+    /// Calculates the adjusted date using the supplied business day convention, like
+    /// [`Business::adjust`], with the `try_` variant of the convention's method.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AccrualError::CalendarExhausted`] if no business day is found before the end of
+    /// the supported date range.
+    fn try_adjust(
+        &self,
+        day: NaiveDate,
+        convention: &BusinessDayConvetion,
+    ) -> Result<NaiveDate, AccrualError> {
+        match convention {
+            BusinessDayConvetion::Following => self.try_following(day),
+            BusinessDayConvetion::EndOfMonthModifiedFollowing if is_end_of_month(day) => {
+                self.try_preceding(day)
+            }
+            BusinessDayConvetion::ModifiedFollowiing
+            | BusinessDayConvetion::EndOfMonthModifiedFollowing => self.try_modified_following(day),
+            BusinessDayConvetion::Preceding => self.try_preceding(day),
+            BusinessDayConvetion::ModifiedPreceding => self.try_modified_preceding(day),
+            BusinessDayConvetion::NoAdjustment => Ok(day),
+        }
+    }
+
+    /// Calculates the adjusted date using the supplied business day convention, like
+    /// [`Business::adjust`], and returns it together with a flag telling whether the adjustment
+    /// moved the date.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn adjust_moved(
+        &self,
+        day: NaiveDate,
+        convention: &BusinessDayConvetion,
+    ) -> Option<(NaiveDate, bool)> {
+        let adjusted = self.adjust(day, convention)?;
+        Some((adjusted, adjusted != day))
+    }
+
+    /// Calculates the adjusted date using the supplied business day convention, like
+    /// [`Business::adjust`], and returns it together with the unadjusted date and the
+    /// convention used.
+    ///
+    /// With [`BusinessDayConvetion::NoAdjustment`], the adjusted date is the unadjusted date,
+    /// even if it is not a business day.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn adjust_recorded(
+        &self,
+        day: NaiveDate,
+        convention: &BusinessDayConvetion,
+    ) -> Option<AdjustedDate> {
+        Some(AdjustedDate {
+            unadjusted: day,
+            adjusted: self.adjust(day, convention)?,
+            convention: *convention,
+        })
+    }
+
+    /// Calculates the adjusted date using the supplied business day convention, like [`adjust`],
+    /// but gives up after checking `max_steps` days in any direction.
+    ///
+    /// This protects against long searches in calendars where every nearby day is a holiday.
+    ///
+    /// Returns `None` if no business day is found within `max_steps` days.
+    ///
+    /// [`adjust`]: Business::adjust
+    fn adjust_within(
+        &self,
+        day: NaiveDate,
+        convention: &BusinessDayConvetion,
+        max_steps: u32,
+    ) -> Option<NaiveDate> {
+        if self.is_business(day) || *convention == BusinessDayConvetion::NoAdjustment {
+            return Some(day);
+        }
+
+        let search =
+            |forward, within_month| bounded_search(self, day, forward, within_month, max_steps);
+        match convention {
+            BusinessDayConvetion::Following => search(true, false).found(),
+            BusinessDayConvetion::EndOfMonthModifiedFollowing if is_end_of_month(day) => {
+                search(false, false).found()
+            }
+            BusinessDayConvetion::ModifiedFollowiing
+            | BusinessDayConvetion::EndOfMonthModifiedFollowing => match search(true, true) {
+                Search::LeftMonth => search(false, false).found(),
+                result => result.found(),
+            },
+            BusinessDayConvetion::Preceding => search(false, false).found(),
+            BusinessDayConvetion::ModifiedPreceding => match search(false, true) {
+                Search::LeftMonth => search(true, false).found(),
+                result => result.found(),
+            },
+            BusinessDayConvetion::NoAdjustment => Some(day),
+        }
+    }
+
+    /// Returns the first `IMM` date strictly after the given date (see [`imm::next_imm`]),
+    /// adjusted using the `following` convention if it is not a business day.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn next_imm_business_day(&self, after: NaiveDate) -> Option<NaiveDate> {
+        self.following(imm::next_imm(after)?)
+    }
+
+    /// Advances the date by the tenor and adjusts the result using the supplied business day
+    /// convention, returning it in a form of `Option<NaiveDate>` enum.
+    ///
+    /// For month and year tenors the end-of-month rule applies: if the supplied date is the last
+    /// day of its month, the unadjusted date is the last day of the resulting month.
+    ///
+    /// Returns `None` if the resulting date is out of range or no such business day exist.
+    fn advance_by_tenor(
+        &self,
+        day: NaiveDate,
+        tenor: &Tenor,
+        convention: &BusinessDayConvetion,
+    ) -> Option<NaiveDate> {
+        self.adjust(tenor.advance(day, true)?, convention)
+    }
+
+    /// Calculates the settlement date of a trade, returning it in a form of `Option<NaiveDate>`
+    /// enum: the spot date is `spot_lag` business days after the `trade` date, which is then
+    /// advanced by the tenor and adjusted using the supplied business day convention, as in
+    /// [`Business::advance_by_tenor`].
+    ///
+    /// Returns `None` if the resulting date is out of range or no such business day exist.
+    fn settlement_from_trade(
+        &self,
+        trade: NaiveDate,
+        spot_lag: i64,
+        tenor: &Tenor,
+        convention: &BusinessDayConvetion,
+    ) -> Option<NaiveDate> {
+        self.advance_by_tenor(self.add_business_days(trade, spot_lag)?, tenor, convention)
+    }
+
+    /// Calculates the fixing date of a floating coupon, `fixing_lag` business days before the
+    /// accrual start, and returns it in a form of `Option<NaiveDate>` enum.
+    ///
+    /// Returns `None` if no such business day exist.
+    fn fixing_date(&self, accrual_start: NaiveDate, fixing_lag: i64) -> Option<NaiveDate> {
+        self.add_business_days(accrual_start, fixing_lag.checked_neg()?)
+    }
+
+    /// Shifts the date by a number of months and adjusts the result using the supplied business
+    /// day convention, returning it in a form of `Option<NaiveDate>` enum.
+    ///
+    /// The day is clamped to the length of the resulting month. If `eom` is set and the supplied
+    /// date is the last day of its month, the unadjusted date is the last day of the resulting
+    /// month, before the adjustment is applied.
+    ///
+    /// Returns `None` if the resulting date is out of range or no such business day exist.
+    fn shift_months_adjusted(
+        &self,
+        day: NaiveDate,
+        months: i32,
+        eom: bool,
+        convention: &BusinessDayConvetion,
+    ) -> Option<NaiveDate> {
+        self.adjust(add_months(day, months, eom)?, convention)
+    }
+
+    /// Returns the first business day of the month in a form of `Option<NaiveDate>` enum, i.e.
+    /// the 1st of the month adjusted using the `following` convention.
+    ///
+    /// Returns `None` if the month is invalid or no such business day exist.
+    fn first_business_day_of_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        self.following(NaiveDate::from_ymd_opt(year, month, 1)?)
+    }
+
+    /// Returns the last business day of the month in a form of `Option<NaiveDate>` enum, i.e.
+    /// the last calendar day of the month adjusted using the `preceding` convention.
+    ///
+    /// Returns `None` if the month is invalid or no such business day exist.
+    fn last_business_day_of_month(&self, year: i32, month: u32) -> Option<NaiveDate> {
+        self.preceding(last_day_of_month(year, month)?)
+    }
+
+    /// Returns the 1-based position of the date among the business days of its month, e.g. `1`
+    /// for the first business day, for rules like a reset on the 5th business day of a month.
+    ///
+    /// Returns `None` if the date is not a business day.
+    fn business_day_index(&self, day: NaiveDate) -> Option<u32> {
+        if !self.is_business(day) {
+            return None;
+        }
+
+        Some(self.count_business_days(day.with_day(1)?, day)? + 1)
+    }
+
+    /// Checks whether the date is the last business day of its month, e.g. Friday, March 29th,
+    /// 2024 in a calendar with a Saturday and Sunday weekend.
+    fn is_month_end(&self, day: NaiveDate) -> bool {
+        self.last_business_day_of_month(day.year(), day.month()) == Some(day)
+    }
+
+    /// Checks whether the date is the last business day of a calendar quarter, i.e. of March,
+    /// June, September or December (see [`Business::is_month_end`]).
+    fn is_quarter_end(&self, day: NaiveDate) -> bool {
+        day.month().is_multiple_of(3) && self.is_month_end(day)
+    }
 }
 
-trait CurrencyCalendar {
-    // TODO
+/// A reference to a calendar is a calendar as well, e.g. to combine borrowed calendars in a
+/// [`joint::JointCalendar`]. The day predicates and the business day searches are delegated to
+/// the referenced calendar, so that overridden searches, e.g. the bit scans of a
+/// [`precomputed::PrecomputedCalendar`], are kept.
+impl<B: Business + ?Sized> Business for &B {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        (**self).is_holiday(day)
+    }
+
+    fn weekend_days(&self) -> &[Weekday] {
+        (**self).weekend_days()
+    }
+
+    fn is_weekend(&self, day: NaiveDate) -> bool {
+        (**self).is_weekend(day)
+    }
+
+    fn is_business(&self, day: NaiveDate) -> bool {
+        (**self).is_business(day)
+    }
+
+    fn has_standard_business_days(&self) -> bool {
+        (**self).has_standard_business_days()
+    }
+
+    fn following(&self, day: NaiveDate) -> Option<NaiveDate> {
+        (**self).following(day)
+    }
+
+    fn try_following(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        (**self).try_following(day)
+    }
+
+    fn modified_following(&self, day: NaiveDate) -> Option<NaiveDate> {
+        (**self).modified_following(day)
+    }
+
+    fn try_modified_following(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        (**self).try_modified_following(day)
+    }
+
+    fn preceding(&self, day: NaiveDate) -> Option<NaiveDate> {
+        (**self).preceding(day)
+    }
+
+    fn try_preceding(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        (**self).try_preceding(day)
+    }
+
+    fn modified_preceding(&self, day: NaiveDate) -> Option<NaiveDate> {
+        (**self).modified_preceding(day)
+    }
+
+    fn try_modified_preceding(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        (**self).try_modified_preceding(day)
+    }
+
+    fn add_business_days(&self, day: NaiveDate, days: i64) -> Option<NaiveDate> {
+        (**self).add_business_days(day, days)
+    }
+
+    fn try_add_business_days(&self, day: NaiveDate, days: i64) -> Result<NaiveDate, AccrualError> {
+        (**self).try_add_business_days(day, days)
+    }
+
+    fn count_business_days(&self, start: NaiveDate, end: NaiveDate) -> Option<u32> {
+        (**self).count_business_days(start, end)
+    }
+}
+
+/// An `AdjustedDate` records a date adjustment: the unadjusted date, the adjusted date and the
+/// business day convention used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdjustedDate {
+    /// The date before the adjustment.
+    pub unadjusted: NaiveDate,
+    /// The date after the adjustment.
+    pub adjusted: NaiveDate,
+    /// The business day convention used.
+    pub convention: BusinessDayConvetion,
+}
+
+/// A `BusinessDaySummary` describes the business days of a date range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BusinessDaySummary {
+    /// The number of business days.
+    pub count: u32,
+    /// The first business day, if there is one.
+    pub first: Option<NaiveDate>,
+    /// The last business day, if there is one.
+    pub last: Option<NaiveDate>,
+}
+
+/// The direction in which a business day search moves through the calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SearchDirection {
+    /// Towards later dates.
+    Forward,
+    /// Towards earlier dates.
+    Backward,
+}
+
+/// A `CalendarExhausted` error is returned if a business day search reaches the end of the
+/// supported date range without finding a business day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarExhausted {
+    /// The date the search started from.
+    pub from: NaiveDate,
+    /// The direction of the search.
+    pub direction: SearchDirection,
+}
+
+impl fmt::Display for CalendarExhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bound = match self.direction {
+            SearchDirection::Forward => NaiveDate::MAX,
+            SearchDirection::Backward => NaiveDate::MIN,
+        };
+        write!(
+            f,
+            "no business day found between {} and {}",
+            self.from, bound
+        )
+    }
+}
+
+impl Error for CalendarExhausted {}
+
+/// Counts the Saturdays and Sundays from `start` (inclusive) to `end` (exclusive) without
+/// iterating over the range.
+///
+/// Returns `0` if `start` is not before `end`.
+#[must_use]
+pub fn weekend_count(start: NaiveDate, end: NaiveDate) -> u32 {
+    if start >= end {
+        return 0;
+    }
+
+    let days = (end - start).num_days();
+    let full_weeks = days / 7;
+    let first = i64::from(start.weekday().num_days_from_monday());
+    let remainder = (0..days % 7)
+        .filter(|offset| (first + offset) % 7 >= 5)
+        .count();
+
+    u32::try_from(full_weeks * 2)
+        .ok()
+        .and_then(|count| count.checked_add(u32::try_from(remainder).ok()?))
+        .unwrap_or(u32::MAX)
+}
+
+/// Checks whether the calendar's business days are the weekdays which are not holidays, so
+/// that the searches can jump over Saturdays and Sundays.
+fn skips_weekends<B: Business + ?Sized>(calendar: &B) -> bool {
+    calendar.has_standard_business_days() && calendar.weekend_days() == SATURDAY_SUNDAY
+}
+
+/// Returns the next day after `day` which may be a business day: the day after, or the
+/// following Monday if `skip_weekends` is set and that day falls on a weekend.
+fn next_candidate(day: NaiveDate, skip_weekends: bool) -> Option<NaiveDate> {
+    let days = match day.weekday() {
+        Weekday::Fri if skip_weekends => 3,
+        Weekday::Sat if skip_weekends => 2,
+        _ => 1,
+    };
+
+    day.checked_add_days(Days::new(days))
+}
+
+/// Returns the previous day before `day` which may be a business day: the day before, or the
+/// preceding Friday if `skip_weekends` is set and that day falls on a weekend.
+fn previous_candidate(day: NaiveDate, skip_weekends: bool) -> Option<NaiveDate> {
+    let days = match day.weekday() {
+        Weekday::Mon if skip_weekends => 3,
+        Weekday::Sun if skip_weekends => 2,
+        _ => 1,
+    };
+
+    day.checked_sub_days(Days::new(days))
+}
+
+/// The outcome of a bounded business day search.
+enum Search {
+    Found(NaiveDate),
+    LeftMonth,
+    Exhausted,
+}
+
+impl Search {
+    fn found(self) -> Option<NaiveDate> {
+        match self {
+            Search::Found(day) => Some(day),
+            Search::LeftMonth | Search::Exhausted => None,
+        }
+    }
+}
+
+/// Searches for the first business day after (or before) `day`, checking at most `max_steps`
+/// days and, if `within_month` is set, stopping at the month boundary.
+fn bounded_search<B: Business + ?Sized>(
+    calendar: &B,
+    day: NaiveDate,
+    forward: bool,
+    within_month: bool,
+    max_steps: u32,
+) -> Search {
+    let mut candidate = day;
+    for _ in 0..max_steps {
+        let next = if forward {
+            candidate.succ_opt()
+        } else {
+            candidate.pred_opt()
+        };
+        match next {
+            Some(next) => candidate = next,
+            None => return Search::Exhausted,
+        }
+
+        if within_month && candidate.month() != day.month() {
+            return Search::LeftMonth;
+        }
+        if calendar.is_business(candidate) {
+            return Search::Found(candidate);
+        }
+    }
+
+    Search::Exhausted
+}
+
+#[allow(dead_code)]
+trait CurrencyCalendar {
+    // TODO
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use super::{joint::JointCalendar, target::TargetCalendar, weekend::WeekendCalendar, *};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn default_weekend_is_saturday_and_sunday() {
+        assert!(!WeekendCalendar.is_weekend(date(2023, 5, 26)));
+        assert!(WeekendCalendar.is_weekend(date(2023, 5, 27)));
+        assert!(WeekendCalendar.is_weekend(date(2023, 5, 28)));
+        assert!(!WeekendCalendar.is_weekend(date(2023, 5, 29)));
+    }
+
+    #[test]
+    fn preceding_searches_backwards() {
+        assert_eq!(
+            WeekendCalendar.preceding(date(2023, 5, 28)),
+            Some(date(2023, 5, 26))
+        );
+        assert_eq!(
+            WeekendCalendar.preceding(date(2023, 5, 26)),
+            Some(date(2023, 5, 26))
+        );
+    }
+
+    #[test]
+    fn advance_by_tenor_end_of_month_modified_following() {
+        // February 28th, 2023 is the last day of the month, so 3M lands on May 31st.
+        let start = date(2023, 2, 28);
+        let manual = TargetCalendar
+            .modified_following(last_day_of_month(2023, 5).unwrap())
+            .unwrap();
+
+        assert_eq!(
+            TargetCalendar.advance_by_tenor(
+                start,
+                &Tenor::months(3),
+                &BusinessDayConvetion::ModifiedFollowiing
+            ),
+            Some(manual)
+        );
+        assert_eq!(manual, date(2023, 5, 31));
+    }
+
+    #[test]
+    fn advance_by_tenor_keeps_day_of_month() {
+        // May 27th, 2023 is a Saturday, rolled to Monday May 29th.
+        assert_eq!(
+            TargetCalendar.advance_by_tenor(
+                date(2023, 2, 27),
+                &Tenor::months(3),
+                &BusinessDayConvetion::ModifiedFollowiing
+            ),
+            Some(date(2023, 5, 29))
+        );
+    }
+
+    #[test]
+    fn advance_by_tenor_modified_following_stays_in_month() {
+        // September 30th, 2023 is a Saturday, so modified following rolls back.
+        let start = date(2023, 6, 30);
+
+        assert_eq!(
+            TargetCalendar.advance_by_tenor(
+                start,
+                &Tenor::months(3),
+                &BusinessDayConvetion::ModifiedFollowiing
+            ),
+            Some(date(2023, 9, 29))
+        );
+        assert_eq!(
+            TargetCalendar.advance_by_tenor(
+                start,
+                &Tenor::months(3),
+                &BusinessDayConvetion::Following
+            ),
+            Some(date(2023, 10, 2))
+        );
+    }
+
+    #[test]
+    fn advance_by_tenor_years_and_days() {
+        assert_eq!(
+            TargetCalendar.advance_by_tenor(
+                date(2023, 2, 28),
+                &Tenor::years(1),
+                &BusinessDayConvetion::NoAdjustment
+            ),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(
+            TargetCalendar.advance_by_tenor(
+                date(2023, 12, 22),
+                &Tenor::days(3),
+                &BusinessDayConvetion::Following
+            ),
+            Some(date(2023, 12, 27))
+        );
+    }
+
+    /// A calendar with 400 consecutive holidays from January 1st, 2020 to February 3rd, 2021.
+    fn long_holiday() -> set::SetCalendar {
+        date(2020, 1, 1).iter_days().take(400).collect()
+    }
+
+    #[test]
+    fn adjust_within_gives_up_after_max_steps() {
+        let calendar = long_holiday();
+        let day = date(2020, 6, 1);
+
+        for convention in [
+            BusinessDayConvetion::Following,
+            BusinessDayConvetion::ModifiedFollowiing,
+            BusinessDayConvetion::Preceding,
+            BusinessDayConvetion::ModifiedPreceding,
+        ] {
+            assert_eq!(calendar.adjust_within(day, &convention, 10), None);
+        }
+    }
+
+    #[test]
+    fn adjust_within_finds_day_within_max_steps() {
+        let calendar = long_holiday();
+        let day = date(2020, 6, 1);
+
+        assert_eq!(
+            calendar.adjust_within(day, &BusinessDayConvetion::Following, 1000),
+            Some(date(2021, 2, 4))
+        );
+        assert_eq!(
+            calendar.adjust_within(day, &BusinessDayConvetion::Preceding, 1000),
+            Some(date(2019, 12, 31))
+        );
+        assert_eq!(
+            calendar.adjust_within(date(2021, 2, 4), &BusinessDayConvetion::Following, 0),
+            Some(date(2021, 2, 4))
+        );
+    }
+
+    #[test]
+    fn modified_following_rolls_back_from_saturday_december_31st() {
+        assert_eq!(
+            WeekendCalendar.modified_following(date(2022, 12, 31)),
+            Some(date(2022, 12, 30))
+        );
+        assert_eq!(
+            TargetCalendar.modified_following(date(2022, 12, 31)),
+            Some(date(2022, 12, 30))
+        );
+    }
+
+    #[test]
+    fn modified_following_rolls_back_from_sunday_december_31st() {
+        assert_eq!(
+            WeekendCalendar.modified_following(date(2023, 12, 31)),
+            Some(date(2023, 12, 29))
+        );
+        assert_eq!(
+            TargetCalendar.modified_following(date(2023, 12, 31)),
+            Some(date(2023, 12, 29))
+        );
+    }
+
+    #[test]
+    fn modified_following_at_max_date() {
+        let adjusted = WeekendCalendar.modified_following(NaiveDate::MAX).unwrap();
+
+        assert!(adjusted <= NaiveDate::MAX);
+        assert!(WeekendCalendar.is_business(adjusted));
+        assert_eq!(
+            (adjusted.year(), adjusted.month()),
+            (NaiveDate::MAX.year(), NaiveDate::MAX.month())
+        );
+    }
+
+    /// Returns pseudo-random ranges of up to about three years between 1995 and 2035, using a
+    /// fixed seed so that failures are reproducible.
+    fn random_ranges(count: usize) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move |bound: u64| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state % bound
+        };
+
+        (0..count)
+            .map(|_| {
+                let start = date(1995, 1, 1) + Days::new(next(40 * 365));
+                (start, start + Days::new(next(3 * 365)))
+            })
+            .collect()
+    }
+
+    fn brute_force_business_days<B: Business + ?Sized>(
+        calendar: &B,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> u32 {
+        let count = start
+            .iter_days()
+            .take_while(|day| *day < end)
+            .filter(|day| calendar.is_business(*day))
+            .count();
+
+        u32::try_from(count).unwrap()
+    }
+
+    #[test]
+    fn weekend_count_matches_brute_force() {
+        for (start, end) in random_ranges(200) {
+            let expected = start
+                .iter_days()
+                .take_while(|day| *day < end)
+                .filter(|day| matches!(day.weekday(), Weekday::Sat | Weekday::Sun))
+                .count();
+
+            assert_eq!(
+                weekend_count(start, end),
+                u32::try_from(expected).unwrap(),
+                "{start} to {end}"
+            );
+        }
+    }
+
+    #[test]
+    fn weekend_count_short_ranges() {
+        // Friday to the following Friday.
+        assert_eq!(weekend_count(date(2024, 5, 3), date(2024, 5, 10)), 2);
+        assert_eq!(weekend_count(date(2024, 5, 4), date(2024, 5, 5)), 1);
+        assert_eq!(weekend_count(date(2024, 5, 6), date(2024, 5, 11)), 0);
+        assert_eq!(weekend_count(date(2024, 5, 6), date(2024, 5, 6)), 0);
+        assert_eq!(weekend_count(date(2024, 5, 10), date(2024, 5, 6)), 0);
+    }
+
+    #[test]
+    fn count_business_days_matches_brute_force() {
+        let calendars: [&dyn Business; 3] = [
+            &WeekendCalendar,
+            &TargetCalendar,
+            &united_states::UnitedStatesCalendar,
+        ];
+
+        for calendar in calendars {
+            assert!(calendar.has_standard_business_days());
+            for (start, end) in random_ranges(50) {
+                assert_eq!(
+                    calendar.count_business_days(start, end),
+                    Some(brute_force_business_days(calendar, start, end)),
+                    "{start} to {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn count_business_days_respects_working_weekends() {
+        use std::collections::{HashMap, HashSet};
+
+        use overlay::CalendarExt;
+
+        // February 4th and 18th, 2024 are Sundays designated as working days.
+        let china = china::ChinaCalendar::new(
+            HashMap::from([(2024, vec![date(2024, 2, 12)])]),
+            HashMap::from([(2024, vec![date(2024, 2, 4), date(2024, 2, 18)])]),
+        );
+        let overridden = TargetCalendar.remove_holidays(HashSet::from([date(2024, 2, 4)]));
+        let (start, end) = (date(2024, 2, 1), date(2024, 3, 1));
+
+        assert!(!china.has_standard_business_days());
+        assert_eq!(china.count_business_days(start, end), Some(22));
+        assert_eq!(
+            china.count_business_days(start, end),
+            Some(brute_force_business_days(&china, start, end))
+        );
+
+        assert!(!overridden.has_standard_business_days());
+        assert_eq!(overridden.count_business_days(start, end), Some(22));
+        assert_eq!(
+            overridden.count_business_days(start, end),
+            Some(brute_force_business_days(&overridden, start, end))
+        );
+    }
+
+    #[test]
+    fn non_business_days_over_two_weeks() {
+        // May 1st, 2024 is the only TARGET holiday in the range.
+        assert_eq!(
+            TargetCalendar.non_business_days(date(2024, 4, 22), date(2024, 5, 6)),
+            vec![
+                date(2024, 4, 27),
+                date(2024, 4, 28),
+                date(2024, 5, 1),
+                date(2024, 5, 4),
+                date(2024, 5, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn non_business_days_excludes_end() {
+        assert_eq!(
+            WeekendCalendar.non_business_days(date(2024, 4, 22), date(2024, 4, 28)),
+            vec![date(2024, 4, 27)]
+        );
+        assert!(WeekendCalendar
+            .non_business_days(date(2024, 4, 28), date(2024, 4, 28))
+            .is_empty());
+    }
+
+    #[test]
+    fn next_imm_business_day_rolls_holiday() {
+        // The June 2024 IMM date is Wednesday June 19th, Juneteenth in the US.
+        let calendar = united_states::UnitedStatesCalendar;
+
+        assert_eq!(imm::next_imm(date(2024, 4, 1)), Some(date(2024, 6, 19)));
+        assert_eq!(
+            calendar.next_imm_business_day(date(2024, 4, 1)),
+            Some(date(2024, 6, 20))
+        );
+        assert_eq!(
+            TargetCalendar.next_imm_business_day(date(2024, 4, 1)),
+            Some(date(2024, 6, 19))
+        );
+    }
+
+    #[test]
+    fn next_imm_business_day_is_strictly_after() {
+        assert_eq!(
+            WeekendCalendar.next_imm_business_day(date(2024, 6, 19)),
+            Some(date(2024, 9, 18))
+        );
+    }
+
+    #[test]
+    fn business_day_aliases_agree() {
+        // The week of Christmas 2024, with two TARGET holidays and a weekend.
+        for day in date(2024, 12, 23).iter_days().take(7) {
+            assert_eq!(
+                TargetCalendar.is_business_day(day),
+                TargetCalendar.is_business(day),
+                "{day}"
+            );
+            assert_eq!(
+                TargetCalendar.is_holiday_or_weekend(day),
+                !TargetCalendar.is_business(day),
+                "{day}"
+            );
+        }
+        assert!(!TargetCalendar.is_business_day(date(2024, 12, 25)));
+        assert!(TargetCalendar.is_holiday_or_weekend(date(2024, 12, 28)));
+        assert!(TargetCalendar.is_business_day(date(2024, 12, 27)));
+    }
+
+    /// Returns the first business day at or after (or before) `day`, stepping one day at a time.
+    fn naive_search<B: Business + ?Sized>(
+        calendar: &B,
+        mut day: NaiveDate,
+        forward: bool,
+    ) -> Option<NaiveDate> {
+        while !calendar.is_business(day) {
+            day = if forward {
+                day.succ_opt()?
+            } else {
+                day.pred_opt()?
+            };
+        }
+
+        Some(day)
+    }
+
+    /// Returns a calendar marking about a third of the days from 2023 to 2025 as holidays,
+    /// using a fixed seed so that failures are reproducible.
+    fn random_holidays() -> set::SetCalendar {
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        date(2023, 1, 1)
+            .iter_days()
+            .take_while(|day| day.year() < 2026)
+            .filter(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state.is_multiple_of(3)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn following_and_preceding_match_naive_search() {
+        let holidays = random_holidays();
+        let calendars: [&dyn Business; 3] = [&holidays, &TargetCalendar, &WeekendCalendar];
+
+        for calendar in calendars {
+            for day in date(2023, 1, 1)
+                .iter_days()
+                .take_while(|day| day.year() < 2026)
+            {
+                assert_eq!(
+                    calendar.following(day),
+                    naive_search(calendar, day, true),
+                    "{day}"
+                );
+                assert_eq!(
+                    calendar.preceding(day),
+                    naive_search(calendar, day, false),
+                    "{day}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn following_skips_weekend_after_friday_holiday() {
+        // Friday March 29th, 2024 is Good Friday and Monday April 1st is Easter Monday.
+        assert_eq!(
+            TargetCalendar.following(date(2024, 3, 29)),
+            Some(date(2024, 4, 2))
+        );
+        assert_eq!(
+            TargetCalendar.preceding(date(2024, 4, 1)),
+            Some(date(2024, 3, 28))
+        );
+        assert_eq!(
+            TargetCalendar.following(date(2024, 3, 30)),
+            Some(date(2024, 4, 2))
+        );
+        assert_eq!(
+            TargetCalendar.preceding(date(2024, 3, 31)),
+            Some(date(2024, 3, 28))
+        );
+    }
+
+    #[test]
+    fn following_and_preceding_at_date_limits() {
+        for day in NaiveDate::MAX.iter_days().rev().take(7) {
+            assert_eq!(
+                WeekendCalendar.following(day),
+                naive_search(&WeekendCalendar, day, true),
+                "{day}"
+            );
+        }
+        for day in NaiveDate::MIN.iter_days().take(7) {
+            assert_eq!(
+                WeekendCalendar.preceding(day),
+                naive_search(&WeekendCalendar, day, false),
+                "{day}"
+            );
+        }
+    }
+
+    #[test]
+    fn business_days_rev_matches_forward_reversed() {
+        let (start, end) = (date(2024, 12, 16), date(2025, 1, 10));
+        let forward: Vec<NaiveDate> = TargetCalendar.business_days(start, end).collect();
+        let mut backward: Vec<NaiveDate> = TargetCalendar.business_days_rev(start, end).collect();
+        backward.reverse();
+
+        assert_eq!(backward, forward);
+        assert_eq!(forward.len(), 16);
+    }
+
+    #[test]
+    fn business_days_rev_bounds() {
+        // `start` is included and `end` excluded, both Mondays here.
+        let (start, end) = (date(2024, 5, 6), date(2024, 5, 13));
+
+        assert_eq!(
+            WeekendCalendar
+                .business_days_rev(start, end)
+                .collect::<Vec<_>>(),
+            vec![
+                date(2024, 5, 10),
+                date(2024, 5, 9),
+                date(2024, 5, 8),
+                date(2024, 5, 7),
+                date(2024, 5, 6),
+            ]
+        );
+        assert_eq!(WeekendCalendar.business_days_rev(end, start).next(), None);
+        assert_eq!(WeekendCalendar.business_days_rev(start, start).next(), None);
+    }
+
+    /// A calendar with every day from the given date onwards a holiday.
+    struct HolidaysFrom(NaiveDate);
+
+    impl Business for HolidaysFrom {
+        fn is_holiday(&self, day: NaiveDate) -> bool {
+            day >= self.0
+        }
+    }
+
+    /// A calendar with every day up to the given date a holiday.
+    struct HolidaysUntil(NaiveDate);
+
+    impl Business for HolidaysUntil {
+        fn is_holiday(&self, day: NaiveDate) -> bool {
+            day <= self.0
+        }
+    }
+
+    #[test]
+    fn following_reports_exhaustion() {
+        let from = NaiveDate::MAX - Days::new(100);
+        let calendar = HolidaysFrom(from);
+
+        assert_eq!(
+            calendar.try_following(from),
+            Err(AccrualError::CalendarExhausted(CalendarExhausted {
+                from,
+                direction: SearchDirection::Forward,
+            }))
+        );
+        assert_eq!(calendar.following(from), None);
+        assert_eq!(
+            calendar.try_following(from).unwrap_err().to_string(),
+            format!(
+                "no business day found between {from} and {}",
+                NaiveDate::MAX
+            )
+        );
+
+        // The search backward from the holidays still finds a business day.
+        assert_eq!(
+            calendar.try_preceding(from),
+            Ok(WeekendCalendar.preceding(from.pred_opt().unwrap()).unwrap())
+        );
+    }
+
+    #[test]
+    fn preceding_reports_exhaustion() {
+        let from = NaiveDate::MIN + Days::new(100);
+        let calendar = HolidaysUntil(from);
+
+        assert_eq!(
+            calendar.try_preceding(from),
+            Err(AccrualError::CalendarExhausted(CalendarExhausted {
+                from,
+                direction: SearchDirection::Backward,
+            }))
+        );
+        assert_eq!(calendar.preceding(from), None);
+        assert_eq!(
+            calendar.try_following(from),
+            Ok(WeekendCalendar.following(from.succ_opt().unwrap()).unwrap())
+        );
+    }
+
+    #[test]
+    fn adjust_moved_on_business_day() {
+        // Wednesday, March 13th, 2024.
+        let day = date(2024, 3, 13);
+
+        for convention in [
+            BusinessDayConvetion::Following,
+            BusinessDayConvetion::ModifiedFollowiing,
+            BusinessDayConvetion::Preceding,
+            BusinessDayConvetion::ModifiedPreceding,
+            BusinessDayConvetion::NoAdjustment,
+        ] {
+            assert_eq!(
+                TargetCalendar.adjust_moved(day, &convention),
+                Some((day, false)),
+                "{convention:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn adjust_moved_on_weekend() {
+        // Saturday, March 16th, 2024.
+        let day = date(2024, 3, 16);
+
+        assert_eq!(
+            TargetCalendar.adjust_moved(day, &BusinessDayConvetion::Following),
+            Some((date(2024, 3, 18), true))
+        );
+        assert_eq!(
+            TargetCalendar.adjust_moved(day, &BusinessDayConvetion::Preceding),
+            Some((date(2024, 3, 15), true))
+        );
+        assert_eq!(
+            TargetCalendar.adjust_moved(day, &BusinessDayConvetion::NoAdjustment),
+            Some((day, false))
+        );
+    }
+
+    #[test]
+    fn business_days_list_between_without_business_days() {
+        // Saturday, December 23rd to Wednesday, December 27th, 2023: a weekend followed by
+        // Christmas Day and Boxing Day.
+        assert_eq!(
+            TargetCalendar.business_days_list_between(date(2023, 12, 23), date(2023, 12, 27)),
+            Some(BusinessDaySummary {
+                count: 0,
+                first: None,
+                last: None,
+            })
+        );
+        assert_eq!(
+            WeekendCalendar.business_days_list_between(date(2024, 3, 16), date(2024, 3, 18)),
+            Some(BusinessDaySummary::default())
+        );
+        assert_eq!(
+            TargetCalendar.business_days_list_between(date(2024, 3, 16), date(2024, 3, 16)),
+            Some(BusinessDaySummary::default())
+        );
+    }
+
+    #[test]
+    fn business_days_list_between_summary() {
+        assert_eq!(
+            TargetCalendar.business_days_list_between(date(2023, 12, 23), date(2024, 1, 3)),
+            Some(BusinessDaySummary {
+                count: 4,
+                first: Some(date(2023, 12, 27)),
+                last: Some(date(2024, 1, 2)),
+            })
+        );
+        assert_eq!(
+            TargetCalendar.business_days_list_between(date(2024, 1, 3), date(2024, 1, 2)),
+            None
+        );
+    }
+
+    /// Asserts that the default calendar observes Christmas Day, Wednesday, December 25th,
+    /// 2024, and rolls over the weekend before.
+    fn assert_default_observes_christmas<C: Business + Default>() {
+        let calendar = C::default();
+
+        assert!(calendar.is_holiday(date(2024, 12, 25)));
+        assert_eq!(
+            calendar.following(date(2024, 12, 21)),
+            Some(date(2024, 12, 23))
+        );
+    }
+
+    #[test]
+    fn default_calendars_are_usable() {
+        let christmas = date(2024, 12, 25);
+
+        assert_default_observes_christmas::<target::TargetCalendar>();
+        assert_default_observes_christmas::<united_states::UnitedStatesCalendar>();
+        assert_default_observes_christmas::<switzerland::SwitzerlandCalendar>();
+        assert_default_observes_christmas::<nordic::NorwayCalendar>();
+        assert_default_observes_christmas::<nordic::SwedenCalendar>();
+        assert_default_observes_christmas::<nordic::DenmarkCalendar>();
+        assert_default_observes_christmas::<hong_kong::HongKongCalendar>();
+
+        let weekend = <WeekendCalendar as Default>::default();
+        assert!(!weekend.is_holiday(christmas));
+        assert_eq!(
+            weekend.following(date(2024, 12, 21)),
+            Some(date(2024, 12, 23))
+        );
+
+        // Table-driven calendars have empty tables, so only the weekends and the computed
+        // holidays, if any, are non-business days. Hong Kong falls back on its built-in dates.
+        assert!(set::SetCalendar::default().is_business(christmas));
+        assert!(hong_kong::HongKongCalendar::default().is_holiday(date(2024, 2, 12)));
+        assert!(india::IndiaCalendar::default().is_business(date(2024, 11, 1)));
+        assert!(!india::IndiaCalendar::default().is_business(date(2024, 1, 26)));
+    }
+
+    #[test]
+    fn shift_months_adjusted_end_of_month() {
+        let following = BusinessDayConvetion::Following;
+        // Monday, February 28th, 2022, the last day of a non-leap February.
+        let day = date(2022, 2, 28);
+
+        assert_eq!(
+            TargetCalendar.shift_months_adjusted(day, 12, true, &following),
+            Some(date(2023, 2, 28))
+        );
+        assert_eq!(
+            TargetCalendar.shift_months_adjusted(day, 24, true, &following),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(
+            TargetCalendar.shift_months_adjusted(day, 24, false, &following),
+            Some(date(2024, 2, 28))
+        );
+    }
+
+    #[test]
+    fn shift_months_adjusted_snaps_before_adjusting() {
+        // March 31st, 2024 is Easter Sunday, and Good Friday precedes it.
+        assert_eq!(
+            TargetCalendar.shift_months_adjusted(
+                date(2024, 1, 31),
+                2,
+                true,
+                &BusinessDayConvetion::ModifiedFollowiing
+            ),
+            Some(date(2024, 3, 28))
+        );
+        assert_eq!(
+            TargetCalendar.shift_months_adjusted(
+                date(2024, 2, 29),
+                1,
+                false,
+                &BusinessDayConvetion::Following
+            ),
+            Some(date(2024, 4, 2))
+        );
+    }
+
+    #[test]
+    fn nearest_business_day_tie_goes_forward() {
+        // Labour Day, Wednesday, May 1st, 2024, between two business days.
+        assert_eq!(
+            TargetCalendar.nearest_business_day(date(2024, 5, 1)),
+            Some(date(2024, 5, 2))
+        );
+        assert_eq!(
+            TargetCalendar.nearest_business_day(date(2024, 5, 2)),
+            Some(date(2024, 5, 2))
+        );
+    }
+
+    #[test]
+    fn nearest_business_day_on_weekend() {
+        // Sunday, March 17th, 2024: Monday is one day away, Friday two.
+        assert_eq!(
+            TargetCalendar.nearest_business_day(date(2024, 3, 17)),
+            Some(date(2024, 3, 18))
+        );
+        // Saturday, March 16th, 2024: Friday is one day away, Monday two.
+        assert_eq!(
+            TargetCalendar.nearest_business_day(date(2024, 3, 16)),
+            Some(date(2024, 3, 15))
+        );
+        // Easter Sunday, March 31st, 2024: Thursday is three days away, Tuesday two.
+        assert_eq!(
+            TargetCalendar.nearest_business_day(date(2024, 3, 31)),
+            Some(date(2024, 4, 2))
+        );
+    }
+
+    /// A calendar with no holidays, counting the calls to [`Business::is_holiday`].
+    #[derive(Default)]
+    struct CountingCalendar {
+        holiday_lookups: Cell<u32>,
+    }
+
+    impl Business for CountingCalendar {
+        fn is_holiday(&self, _day: NaiveDate) -> bool {
+            self.holiday_lookups.set(self.holiday_lookups.get() + 1);
+            false
+        }
+    }
+
+    #[test]
+    fn is_business_skips_holiday_lookup_on_weekends() {
+        let calendar = CountingCalendar::default();
+
+        // Monday, March 11th to Sunday, March 17th, 2024.
+        let business_days = date(2024, 3, 11)
+            .iter_days()
+            .take(7)
+            .filter(|day| calendar.is_business(*day))
+            .count();
+
+        assert_eq!(business_days, 5);
+        assert_eq!(calendar.holiday_lookups.get(), 5);
+    }
+
+    #[test]
+    fn adjustments_at_date_range_ends_do_not_overflow() {
+        let conventions = [
+            BusinessDayConvetion::Following,
+            BusinessDayConvetion::ModifiedFollowiing,
+            BusinessDayConvetion::Preceding,
+            BusinessDayConvetion::ModifiedPreceding,
+        ];
+        let at_max = HolidaysFrom(NaiveDate::MAX);
+        let at_min = HolidaysUntil(NaiveDate::MIN);
+
+        // Only the search running off the end of the range fails; the modified conventions
+        // turn back into the month instead.
+        for convention in &conventions {
+            let expected = match convention {
+                BusinessDayConvetion::Following => None,
+                _ => WeekendCalendar.preceding(NaiveDate::MAX.pred_opt().unwrap()),
+            };
+            assert_eq!(
+                at_max.adjust(NaiveDate::MAX, convention),
+                expected,
+                "{convention:?}"
+            );
+
+            let expected = match convention {
+                BusinessDayConvetion::Preceding => None,
+                _ => WeekendCalendar.following(NaiveDate::MIN.succ_opt().unwrap()),
+            };
+            assert_eq!(
+                at_min.adjust(NaiveDate::MIN, convention),
+                expected,
+                "{convention:?}"
+            );
+        }
+
+        assert!(at_max.try_following(NaiveDate::MAX).is_err());
+        assert!(at_min.try_preceding(NaiveDate::MIN).is_err());
+    }
+
+    #[test]
+    fn no_adjustment_keeps_sunday() {
+        // Sunday, March 17th, 2024.
+        let sunday = date(2024, 3, 17);
+
+        assert_eq!(
+            TargetCalendar.adjust(sunday, &BusinessDayConvetion::NoAdjustment),
+            Some(sunday)
+        );
+        assert_eq!(
+            TargetCalendar.adjust_recorded(sunday, &BusinessDayConvetion::NoAdjustment),
+            Some(AdjustedDate {
+                unadjusted: sunday,
+                adjusted: sunday,
+                convention: BusinessDayConvetion::NoAdjustment,
+            })
+        );
+    }
+
+    #[test]
+    fn adjust_recorded_keeps_provenance() {
+        let sunday = date(2024, 3, 17);
+
+        assert_eq!(
+            TargetCalendar.adjust_recorded(sunday, &BusinessDayConvetion::Preceding),
+            Some(AdjustedDate {
+                unadjusted: sunday,
+                adjusted: date(2024, 3, 15),
+                convention: BusinessDayConvetion::Preceding,
+            })
+        );
+        assert_eq!(
+            HolidaysFrom(NaiveDate::MAX)
+                .adjust_recorded(NaiveDate::MAX, &BusinessDayConvetion::Following),
+            None
+        );
+    }
+
+    #[test]
+    fn first_and_last_business_day_of_month() {
+        let us = united_states::UnitedStatesCalendar;
+
+        // May 2021 starts on a Saturday and ends on Memorial Day, Monday, May 31st.
+        assert_eq!(
+            us.first_business_day_of_month(2021, 5),
+            Some(date(2021, 5, 3))
+        );
+        assert_eq!(
+            us.last_business_day_of_month(2021, 5),
+            Some(date(2021, 5, 28))
+        );
+
+        // January 2022 starts on New Year's Day, a Saturday.
+        assert_eq!(
+            TargetCalendar.first_business_day_of_month(2022, 1),
+            Some(date(2022, 1, 3))
+        );
+        assert_eq!(
+            TargetCalendar.last_business_day_of_month(2022, 1),
+            Some(date(2022, 1, 31))
+        );
+        assert_eq!(
+            TargetCalendar.last_business_day_of_month(2024, 2),
+            Some(date(2024, 2, 29))
+        );
+        assert_eq!(TargetCalendar.first_business_day_of_month(2024, 13), None);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn count_business_days_batch_matches_sequential() {
+        let mut ranges = random_ranges(1000);
+        ranges.push((date(2024, 3, 18), date(2024, 3, 11)));
+        ranges.push((date(2024, 3, 18), date(2024, 3, 18)));
+
+        let sequential: Vec<Option<u32>> = ranges
+            .iter()
+            .map(|(start, end)| TargetCalendar.count_business_days(*start, *end))
+            .collect();
+
+        assert_eq!(
+            TargetCalendar.count_business_days_batch(&ranges),
+            sequential
+        );
+        assert_eq!(TargetCalendar.count_business_days_batch(&[]), []);
+    }
+
+    #[test]
+    fn settlement_from_trade_monday_spot_3m() {
+        let modified_following = BusinessDayConvetion::ModifiedFollowiing;
+
+        // Trade on Monday, March 18th, 2024, spot on Wednesday, March 20th.
+        assert_eq!(
+            TargetCalendar.settlement_from_trade(
+                date(2024, 3, 18),
+                2,
+                &Tenor::months(3),
+                &modified_following
+            ),
+            Some(date(2024, 6, 20))
+        );
+        // Trade on Monday, September 23rd, 2024: three months after the spot date is
+        // Christmas Day, so the settlement rolls over Boxing Day as well.
+        assert_eq!(
+            TargetCalendar.settlement_from_trade(
+                date(2024, 9, 23),
+                2,
+                &Tenor::months(3),
+                &modified_following
+            ),
+            Some(date(2024, 12, 27))
+        );
+        // Trade on Monday, January 30th, 2023: the spot date is the 1st of February, and
+        // three months later is Labour Day.
+        assert_eq!(
+            TargetCalendar.settlement_from_trade(
+                date(2023, 1, 30),
+                2,
+                &Tenor::months(3),
+                &modified_following
+            ),
+            Some(date(2023, 5, 2))
+        );
+    }
+
+    #[test]
+    fn fixing_date_two_days_before() {
+        // Accrual starting on Tuesday, March 19th, 2024 fixes on the Friday before.
+        assert_eq!(
+            TargetCalendar.fixing_date(date(2024, 3, 19), 2),
+            Some(date(2024, 3, 15))
+        );
+        // Accrual starting on Wednesday, April 3rd, 2024 fixes on the Thursday before Easter,
+        // skipping Easter Monday and Good Friday.
+        assert_eq!(
+            TargetCalendar.fixing_date(date(2024, 4, 3), 2),
+            Some(date(2024, 3, 28))
+        );
+        assert_eq!(
+            TargetCalendar.fixing_date(date(2024, 3, 19), 0),
+            Some(date(2024, 3, 19))
+        );
+    }
+
+    #[test]
+    fn period_year_fraction_bus_252_and_act_360() {
+        let brazil = latin_america::BrazilCalendar;
+        let (start, end) = (date(2024, 1, 1), date(2024, 2, 1));
+
+        // January 2024 has 23 weekdays, one of them New Year's Day.
+        assert_eq!(
+            brazil.period_year_fraction(start, end, &DayCountConvention::Business252),
+            Some(dec!(22) / dec!(252))
+        );
+        assert_eq!(
+            WeekendCalendar.period_year_fraction(start, end, &DayCountConvention::Business252),
+            Some(dec!(23) / dec!(252))
+        );
+        assert_eq!(
+            brazil.period_year_fraction(start, end, &DayCountConvention::Actual360),
+            Some(dec!(31) / dec!(360))
+        );
+        assert_eq!(
+            brazil.period_year_fraction(end, start, &DayCountConvention::Business252),
+            None
+        );
+    }
+
+    #[test]
+    fn business_days_until_is_signed() {
+        // Monday, March 11th to Monday, March 18th, 2024.
+        let (from, to) = (date(2024, 3, 11), date(2024, 3, 18));
+
+        assert_eq!(TargetCalendar.business_days_until(from, to), 5);
+        assert_eq!(TargetCalendar.business_days_until(to, from), -5);
+        assert_eq!(TargetCalendar.business_days_until(from, from), 0);
+        // Over Easter 2024.
+        assert_eq!(
+            TargetCalendar.business_days_until(date(2024, 3, 28), date(2024, 4, 3)),
+            2
+        );
+    }
+
+    /// A calendar with a Friday and Saturday weekend and no holidays.
+    struct FridaySaturdayCalendar;
+
+    impl Business for FridaySaturdayCalendar {
+        fn is_holiday(&self, _day: NaiveDate) -> bool {
+            false
+        }
+
+        fn weekend_days(&self) -> &[Weekday] {
+            &[Weekday::Fri, Weekday::Sat]
+        }
+    }
+
+    #[test]
+    fn adjustments_respect_overridden_weekend() {
+        let calendar = FridaySaturdayCalendar;
+        // Friday, March 15th, 2024.
+        let friday = date(2024, 3, 15);
+
+        assert!(calendar.is_weekend(friday));
+        assert!(calendar.is_business(date(2024, 3, 17)));
+        assert_eq!(calendar.following(friday), Some(date(2024, 3, 17)));
+        assert_eq!(
+            calendar.preceding(date(2024, 3, 16)),
+            Some(date(2024, 3, 14))
+        );
+        assert_eq!(
+            calendar.adjust(friday, &BusinessDayConvetion::ModifiedFollowiing),
+            Some(date(2024, 3, 17))
+        );
+        assert_eq!(
+            calendar.adjust(friday, &BusinessDayConvetion::ModifiedPreceding),
+            Some(date(2024, 3, 14))
+        );
+
+        // Friday, May 31st, 2024 is the last day of the month.
+        assert_eq!(
+            calendar.adjust(date(2024, 5, 31), &BusinessDayConvetion::ModifiedFollowiing),
+            Some(date(2024, 5, 30))
+        );
+        // Saturday, June 1st, 2024 is the first day of the month.
+        assert_eq!(
+            calendar.adjust(date(2024, 6, 1), &BusinessDayConvetion::ModifiedPreceding),
+            Some(date(2024, 6, 2))
+        );
+        assert_eq!(
+            calendar.count_business_days(friday, date(2024, 3, 22)),
+            Some(5)
+        );
+    }
+
+    #[test]
+    fn end_of_month_modified_following_february() {
+        let convention = BusinessDayConvetion::EndOfMonthModifiedFollowing;
+
+        // February 28th, 2026 is a Saturday.
+        assert_eq!(
+            TargetCalendar.end_of_month_modified_following(date(2026, 2, 28)),
+            Some(date(2026, 2, 27))
+        );
+        assert_eq!(
+            TargetCalendar.adjust(date(2026, 2, 28), &convention),
+            Some(date(2026, 2, 27))
+        );
+        assert_eq!(
+            TargetCalendar.adjust_within(date(2026, 2, 28), &convention, 10),
+            Some(date(2026, 2, 27))
+        );
+        // February 29th, 2024 is a Thursday.
+        assert_eq!(
+            TargetCalendar.adjust(date(2024, 2, 29), &convention),
+            Some(date(2024, 2, 29))
+        );
+        // Not a month end: Saturday, February 14th, 2026 rolls forward.
+        assert_eq!(
+            TargetCalendar.adjust(date(2026, 2, 14), &convention),
+            Some(date(2026, 2, 16))
+        );
+    }
+
+    #[test]
+    fn boxed_calendar_is_dyn_compatible() {
+        let calendar: Box<dyn Business> = Box::new(TargetCalendar);
+        // Good Friday, March 29th, 2024.
+        let good_friday = date(2024, 3, 29);
+
+        assert_eq!(calendar.following(good_friday), Some(date(2024, 4, 2)));
+        assert_eq!(calendar.preceding(good_friday), Some(date(2024, 3, 28)));
+        assert_eq!(
+            calendar.adjust(good_friday, &BusinessDayConvetion::ModifiedFollowiing),
+            Some(date(2024, 3, 28))
+        );
+        assert_eq!(
+            calendar.count_business_days(date(2024, 3, 25), date(2024, 4, 8)),
+            Some(8)
+        );
+
+        // Calendars selected at runtime can be combined behind the same type.
+        let calendars: Vec<Box<dyn Business>> = vec![calendar, Box::new(WeekendCalendar)];
+        assert_eq!(
+            calendars
+                .iter()
+                .filter(|calendar| calendar.is_business(good_friday))
+                .count(),
+            1
+        );
+    }
+
+    /// A broken calendar whose `following` returns the date unchanged, even on weekends.
+    #[cfg(debug_assertions)]
+    struct BrokenFollowingCalendar;
+
+    #[cfg(debug_assertions)]
+    impl Business for BrokenFollowingCalendar {
+        fn is_holiday(&self, _day: NaiveDate) -> bool {
+            false
+        }
+
+        fn following(&self, day: NaiveDate) -> Option<NaiveDate> {
+            Some(day)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "which is not a business day")]
+    #[cfg(debug_assertions)]
+    fn adjust_asserts_business_day_in_debug() {
+        // Saturday, March 16th, 2024.
+        BrokenFollowingCalendar.adjust(date(2024, 3, 16), &BusinessDayConvetion::Following);
+    }
+
+    /// A broken calendar whose `preceding` returns the next Monday.
+    #[cfg(debug_assertions)]
+    struct BrokenPrecedingCalendar;
+
+    #[cfg(debug_assertions)]
+    impl Business for BrokenPrecedingCalendar {
+        fn is_holiday(&self, _day: NaiveDate) -> bool {
+            false
+        }
+
+        fn preceding(&self, day: NaiveDate) -> Option<NaiveDate> {
+            self.following(day)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "in the wrong direction")]
+    #[cfg(debug_assertions)]
+    fn adjust_asserts_direction_in_debug() {
+        BrokenPrecedingCalendar.adjust(date(2024, 3, 16), &BusinessDayConvetion::Preceding);
+    }
+
+    #[test]
+    fn non_holiday_days_counts_weekends_but_not_holidays() {
+        // May 1st, 2024 is a Wednesday and a TARGET holiday; May 4th and 5th are a weekend.
+        let (start, end) = (date(2024, 4, 29), date(2024, 5, 6));
+        assert_eq!(TargetCalendar.non_holiday_days(start, end), Some(6));
+        assert_eq!(TargetCalendar.count_business_days(start, end), Some(4));
+        assert_eq!(WeekendCalendar.non_holiday_days(start, end), Some(7));
+    }
+
+    #[test]
+    fn non_holiday_days_empty_and_reversed_ranges() {
+        let day = date(2024, 5, 1);
+        assert_eq!(TargetCalendar.non_holiday_days(day, day), Some(0));
+        assert_eq!(
+            TargetCalendar.non_holiday_days(day, date(2024, 4, 30)),
+            None
+        );
+    }
+
+    #[test]
+    fn quarter_end_when_march_31st_is_a_weekend() {
+        // March 31st, 2024 is a Sunday, and Good Friday, March 29th, is a TARGET holiday.
+        assert!(WeekendCalendar.is_quarter_end(date(2024, 3, 29)));
+        assert!(!WeekendCalendar.is_quarter_end(date(2024, 3, 31)));
+        assert!(TargetCalendar.is_quarter_end(date(2024, 3, 28)));
+        assert!(!TargetCalendar.is_quarter_end(date(2024, 3, 29)));
+        assert!(WeekendCalendar.is_quarter_end(date(2024, 6, 28)));
+    }
+
+    #[test]
+    fn month_end_is_not_always_quarter_end() {
+        assert!(WeekendCalendar.is_month_end(date(2024, 4, 30)));
+        assert!(!WeekendCalendar.is_quarter_end(date(2024, 4, 30)));
+        assert!(!WeekendCalendar.is_month_end(date(2024, 4, 29)));
+        assert!(WeekendCalendar.is_quarter_end(date(2024, 12, 31)));
+    }
+
+    #[test]
+    fn adjust_matches_quantlib_vectors() {
+        use BusinessDayConvetion::{Following, ModifiedFollowiing, ModifiedPreceding, Preceding};
+
+        let target = TargetCalendar;
+        let us = united_states::UnitedStatesCalendar;
+        // (input date, calendar, convention, expected adjusted date), as adjusted by
+        // QuantLib's TARGET and UnitedStates(GovernmentBond) calendars, one vector per line.
+        #[rustfmt::skip]
+        let vectors: [(NaiveDate, &dyn Business, BusinessDayConvetion, NaiveDate); 29] = [
+            (date(2024, 3, 29), &target, Following, date(2024, 4, 2)),
+            (date(2024, 3, 29), &target, ModifiedFollowiing, date(2024, 3, 28)),
+            (date(2024, 3, 29), &target, Preceding, date(2024, 3, 28)),
+            (date(2024, 4, 1), &target, ModifiedPreceding, date(2024, 4, 2)),
+            (date(2024, 12, 25), &target, Following, date(2024, 12, 27)),
+            (date(2023, 12, 30), &target, ModifiedFollowiing, date(2023, 12, 29)),
+            (date(2024, 5, 1), &target, Following, date(2024, 5, 2)),
+            (date(2022, 12, 26), &target, Following, date(2022, 12, 27)),
+            (date(2022, 1, 1), &target, Following, date(2022, 1, 3)),
+            (date(2021, 12, 25), &target, Preceding, date(2021, 12, 24)),
+            (date(2024, 7, 4), &us, Following, date(2024, 7, 5)),
+            (date(2021, 7, 4), &us, Following, date(2021, 7, 6)),
+            (date(2020, 7, 4), &us, Preceding, date(2020, 7, 2)),
+            (date(2024, 11, 28), &us, Following, date(2024, 11, 29)),
+            (date(2024, 8, 31), &us, ModifiedFollowiing, date(2024, 8, 30)),
+            (date(2024, 9, 2), &us, Preceding, date(2024, 8, 30)),
+            (date(2024, 9, 2), &us, ModifiedPreceding, date(2024, 9, 3)),
+            (date(2024, 1, 15), &us, Following, date(2024, 1, 16)),
+            (date(2024, 2, 19), &us, Following, date(2024, 2, 20)),
+            (date(2024, 5, 27), &us, Preceding, date(2024, 5, 24)),
+            (date(2023, 6, 19), &us, Following, date(2023, 6, 20)),
+            (date(2024, 10, 14), &us, Following, date(2024, 10, 15)),
+            (date(2022, 12, 25), &us, Following, date(2022, 12, 27)),
+            // Good Friday, 2025 is a holiday, while Good Friday, 2021 falls on the release of
+            // the nonfarm payrolls, and Easter Monday is not a holiday.
+            (date(2025, 4, 18), &us, Following, date(2025, 4, 21)),
+            (date(2025, 4, 18), &us, Preceding, date(2025, 4, 17)),
+            (date(2021, 4, 2), &us, Following, date(2021, 4, 2)),
+            // Veterans Day is not moved from Saturday, November 11th, 2023 to the Friday, but
+            // is moved from Sunday, November 11th, 2029 to the Monday.
+            (date(2023, 11, 11), &us, Preceding, date(2023, 11, 10)),
+            (date(2023, 11, 10), &us, Following, date(2023, 11, 10)),
+            (date(2029, 11, 12), &us, Following, date(2029, 11, 13)),
+        ];
+
+        for (day, calendar, convention, expected) in vectors {
+            assert_eq!(
+                calendar.adjust(day, &convention),
+                Some(expected),
+                "{day} {convention:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn business_day_index_after_a_holiday() {
+        // May 1st, 2024 is a Wednesday and a TARGET holiday.
+        assert_eq!(TargetCalendar.business_day_index(date(2024, 5, 2)), Some(1));
+        assert_eq!(TargetCalendar.business_day_index(date(2024, 5, 6)), Some(3));
+        assert_eq!(
+            TargetCalendar.business_day_index(date(2024, 5, 31)),
+            Some(22)
+        );
+        assert_eq!(
+            WeekendCalendar.business_day_index(date(2024, 5, 6)),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn business_day_index_of_non_business_days() {
+        assert_eq!(TargetCalendar.business_day_index(date(2024, 5, 1)), None);
+        assert_eq!(TargetCalendar.business_day_index(date(2024, 5, 4)), None);
+        assert_eq!(
+            WeekendCalendar.business_day_index(date(2024, 5, 1)),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn holidays_between_includes_saturday_holiday() {
+        // December 25th, 2020 is a Friday and December 26th a Saturday.
+        let (start, end) = (date(2020, 10, 1), date(2021, 1, 1));
+        assert_eq!(
+            TargetCalendar.holidays_between(start, end),
+            vec![date(2020, 12, 25), date(2020, 12, 26)]
+        );
+        assert_eq!(TargetCalendar.non_business_days(start, end).len(), 27);
+        assert!(WeekendCalendar.holidays_between(start, end).is_empty());
+    }
+
+    #[test]
+    fn holidays_between_excludes_end() {
+        assert_eq!(
+            TargetCalendar.holidays_between(date(2020, 12, 25), date(2020, 12, 26)),
+            vec![date(2020, 12, 25)]
+        );
+        assert!(TargetCalendar
+            .holidays_between(date(2020, 12, 26), date(2020, 12, 25))
+            .is_empty());
+    }
+
+    #[test]
+    fn next_n_business_days_skips_weekend_and_holiday() {
+        // May 1st, 2024 is a Wednesday and a TARGET holiday.
+        let days = TargetCalendar.next_n_business_days(date(2024, 4, 30), 4);
+        assert_eq!(
+            days,
+            vec![
+                date(2024, 5, 2),
+                date(2024, 5, 3),
+                date(2024, 5, 6),
+                date(2024, 5, 7)
+            ]
+        );
+        assert_eq!(
+            TargetCalendar.next_n_business_days(date(2024, 5, 1), 2),
+            vec![date(2024, 5, 2), date(2024, 5, 3)]
+        );
+        assert!(TargetCalendar
+            .next_n_business_days(date(2024, 4, 30), 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn next_n_business_days_at_end_of_range() {
+        let last = WeekendCalendar.preceding(NaiveDate::MAX).unwrap();
+        let before = WeekendCalendar.add_business_days(last, -1).unwrap();
+        assert_eq!(WeekendCalendar.next_n_business_days(before, 3), vec![last]);
+    }
+
+    /// Returns the error of a search from the date exhausting the date range in the direction.
+    fn exhausted(from: NaiveDate, direction: SearchDirection) -> AccrualError {
+        AccrualError::CalendarExhausted(CalendarExhausted { from, direction })
+    }
+
+    #[test]
+    fn try_methods_on_all_holidays_calendar() {
+        let calendar = HolidaysFrom(NaiveDate::MIN);
+        let late = NaiveDate::MAX - Days::new(10);
+        let early = NaiveDate::MIN + Days::new(10);
+
+        assert_eq!(
+            calendar.try_following(late),
+            Err(exhausted(late, SearchDirection::Forward))
+        );
+        assert_eq!(
+            calendar.try_preceding(early),
+            Err(exhausted(early, SearchDirection::Backward))
+        );
+        assert_eq!(
+            calendar.try_add_business_days(late, 5),
+            Err(exhausted(late, SearchDirection::Forward))
+        );
+        assert_eq!(
+            calendar.try_add_business_days(early, -5),
+            Err(exhausted(early, SearchDirection::Backward))
+        );
+        assert_eq!(
+            calendar.try_adjust(late, &BusinessDayConvetion::Following),
+            Err(exhausted(late, SearchDirection::Forward))
+        );
+        assert_eq!(
+            calendar.try_adjust(early, &BusinessDayConvetion::Preceding),
+            Err(exhausted(early, SearchDirection::Backward))
+        );
+        assert_eq!(
+            calendar.try_adjust(late, &BusinessDayConvetion::NoAdjustment),
+            Ok(late)
+        );
+    }
+
+    #[test]
+    fn try_modified_methods_on_all_holidays_calendar() {
+        // The searches start in the first and the last month of the date range, so they only
+        // cover a few days before reaching its ends.
+        let calendar = HolidaysFrom(NaiveDate::MIN);
+        let early = NaiveDate::MIN + Days::new(10);
+        let late = NaiveDate::MAX - Days::new(10);
+
+        assert_eq!(
+            calendar.try_modified_following(early),
+            Err(exhausted(early, SearchDirection::Backward))
+        );
+        assert_eq!(
+            calendar.try_modified_preceding(late),
+            Err(exhausted(late, SearchDirection::Forward))
+        );
+        assert_eq!(
+            calendar.try_adjust(early, &BusinessDayConvetion::ModifiedFollowiing),
+            Err(exhausted(early, SearchDirection::Backward))
+        );
+    }
+
+    #[test]
+    fn try_methods_return_business_day_unchanged() {
+        // Wednesday, March 13th, 2024.
+        let day = date(2024, 3, 13);
+
+        assert_eq!(TargetCalendar.try_following(day), Ok(day));
+        assert_eq!(TargetCalendar.try_preceding(day), Ok(day));
+        assert_eq!(TargetCalendar.try_modified_following(day), Ok(day));
+        assert_eq!(TargetCalendar.try_modified_preceding(day), Ok(day));
+        assert_eq!(TargetCalendar.try_add_business_days(day, 0), Ok(day));
+        assert_eq!(
+            TargetCalendar.try_adjust(date(2024, 3, 16), &BusinessDayConvetion::ModifiedFollowiing),
+            Ok(date(2024, 3, 18))
+        );
+    }
+
+    #[test]
+    fn accrual_error_display_and_source() {
+        let from = date(2024, 3, 16);
+        let error = exhausted(from, SearchDirection::Forward);
+
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "no business day found between {from} and {}",
+                NaiveDate::MAX
+            )
+        );
+        assert_eq!(
+            Error::source(&error).map(ToString::to_string),
+            Some(error.to_string())
+        );
+    }
+
+    /// Searches every day onto `NaiveDate::MIN`, so that a search falling back to the trait
+    /// defaults is told apart.
+    struct OverriddenSearchCalendar;
+
+    impl Business for OverriddenSearchCalendar {
+        fn is_holiday(&self, _day: NaiveDate) -> bool {
+            false
+        }
+
+        fn try_following(&self, _day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+            Ok(NaiveDate::MIN)
+        }
+
+        fn try_preceding(&self, _day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+            Ok(NaiveDate::MIN)
+        }
+    }
+
+    #[test]
+    fn reference_keeps_overridden_searches() {
+        let day = date(2024, 3, 11);
+        let reference = &&OverriddenSearchCalendar;
+        assert_eq!(reference.try_following(day), Ok(NaiveDate::MIN));
+        assert_eq!(reference.try_preceding(day), Ok(NaiveDate::MIN));
+
+        let joint = JointCalendar::from_slice(&[&OverriddenSearchCalendar]);
+        let borrowed = &joint.calendars()[0];
+        assert_eq!(borrowed.following(day), Some(NaiveDate::MIN));
+        assert_eq!(borrowed.preceding(day), Some(NaiveDate::MIN));
+        assert_eq!(borrowed.try_preceding(day), Ok(NaiveDate::MIN));
+    }
 }
@@ -0,0 +1,194 @@
+//! This module provides the Norwegian, Swedish and Danish bank holiday calendars.
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::calendar::{
+    holidays::{easter_offset, weekday_on_or_after},
+    Business,
+};
+
+/// `NorwayCalendar` is the bank holiday calendar of Oslo, used for `NOK` settlement.
+///
+/// The holidays are New Year's Day, Maundy Thursday, Good Friday, Easter Monday, Labour Day,
+/// Constitution Day (May 17th), Ascension Day, Whit Monday, Christmas Eve, Christmas Day,
+/// Boxing Day and New Year's Eve.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NorwayCalendar;
+
+impl NorwayCalendar {
+    /// Returns the bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            easter_offset(year, -3),
+            easter_offset(year, -2),
+            easter_offset(year, 1),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            NaiveDate::from_ymd_opt(year, 5, 17),
+            easter_offset(year, 39),
+            easter_offset(year, 50),
+            NaiveDate::from_ymd_opt(year, 12, 24),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+            NaiveDate::from_ymd_opt(year, 12, 26),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+}
+
+impl Business for NorwayCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+/// `SwedenCalendar` is the bank holiday calendar of Stockholm, used for `SEK` settlement.
+///
+/// The holidays are New Year's Day, Epiphany, Good Friday, Easter Monday, Labour Day, Ascension
+/// Day, National Day (June 6th), Midsummer Eve (the Friday between June 19th and 25th),
+/// Christmas Eve, Christmas Day, Boxing Day and New Year's Eve.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwedenCalendar;
+
+impl SwedenCalendar {
+    /// Returns the bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let midsummer_eve = NaiveDate::from_ymd_opt(year, 6, 19)
+            .and_then(|day| weekday_on_or_after(day, Weekday::Fri));
+
+        let mut holidays: Vec<NaiveDate> = [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 1, 6),
+            easter_offset(year, -2),
+            easter_offset(year, 1),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            easter_offset(year, 39),
+            NaiveDate::from_ymd_opt(year, 6, 6),
+            midsummer_eve,
+            NaiveDate::from_ymd_opt(year, 12, 24),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+            NaiveDate::from_ymd_opt(year, 12, 26),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+}
+
+impl Business for SwedenCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+/// `DenmarkCalendar` is the bank holiday calendar of Copenhagen, used for `DKK` settlement.
+///
+/// The holidays are New Year's Day, Maundy Thursday, Good Friday, Easter Monday, General Prayer
+/// Day (until 2023), Ascension Day and the day after it, Whit Monday, Constitution Day
+/// (June 5th), Christmas Eve, Christmas Day, Boxing Day and New Year's Eve.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DenmarkCalendar;
+
+impl DenmarkCalendar {
+    /// Returns the bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let general_prayer_day = if year < 2024 {
+            easter_offset(year, 26)
+        } else {
+            None
+        };
+
+        let mut holidays: Vec<NaiveDate> = [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            easter_offset(year, -3),
+            easter_offset(year, -2),
+            easter_offset(year, 1),
+            general_prayer_day,
+            easter_offset(year, 39),
+            easter_offset(year, 40),
+            easter_offset(year, 50),
+            NaiveDate::from_ymd_opt(year, 6, 5),
+            NaiveDate::from_ymd_opt(year, 12, 24),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+            NaiveDate::from_ymd_opt(year, 12, 26),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        holidays.sort_unstable();
+        holidays.dedup();
+        holidays
+    }
+}
+
+impl Business for DenmarkCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn sweden_midsummer_eve() {
+        assert!(SwedenCalendar.is_holiday(date(2023, 6, 23)));
+        assert!(SwedenCalendar.is_holiday(date(2024, 6, 21)));
+        assert!(SwedenCalendar.is_holiday(date(2025, 6, 20)));
+        assert!(!SwedenCalendar.is_holiday(date(2024, 6, 20)));
+        assert!(!SwedenCalendar.is_holiday(date(2024, 6, 24)));
+    }
+
+    #[test]
+    fn norway_constitution_day() {
+        assert!(NorwayCalendar.is_holiday(date(2024, 5, 17)));
+        assert!(NorwayCalendar.is_holiday(date(2025, 5, 17)));
+        assert!(!NorwayCalendar.is_holiday(date(2024, 5, 16)));
+        assert!(!SwedenCalendar.is_holiday(date(2024, 5, 17)));
+    }
+
+    #[test]
+    fn denmark_general_prayer_day_until_2023() {
+        // The fourth Friday after Easter, abolished from 2024.
+        assert!(DenmarkCalendar.is_holiday(date(2023, 5, 5)));
+        assert!(!DenmarkCalendar.is_holiday(date(2024, 4, 26)));
+        assert!(DenmarkCalendar.is_holiday(date(2024, 6, 5)));
+    }
+
+    #[test]
+    fn easter_holidays_2024() {
+        // Easter Sunday 2024 is March 31st.
+        assert!(NorwayCalendar.is_holiday(date(2024, 3, 28)));
+        assert!(!SwedenCalendar.is_holiday(date(2024, 3, 28)));
+        assert!(DenmarkCalendar.is_holiday(date(2024, 3, 28)));
+        for calendar in [
+            &NorwayCalendar as &dyn Business,
+            &SwedenCalendar,
+            &DenmarkCalendar,
+        ] {
+            assert!(calendar.is_holiday(date(2024, 3, 29)));
+            assert!(calendar.is_holiday(date(2024, 4, 1)));
+            assert!(calendar.is_holiday(date(2024, 5, 9)));
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! This module provides calendars layering changes on top of a base calendar.
+
+use std::collections::HashSet;
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::calendar::Business;
+
+/// `AdditionalHolidays` is a calendar with extra holidays, e.g. firm-specific closures, added
+/// on top of the holidays of a base calendar.
+#[derive(Debug, Clone)]
+pub struct AdditionalHolidays<C: Business> {
+    base: C,
+    extra: HashSet<NaiveDate>,
+}
+
+impl<C: Business> AdditionalHolidays<C> {
+    /// Creates a calendar adding the `extra` holidays to the `base` calendar.
+    #[must_use]
+    pub fn new(base: C, extra: HashSet<NaiveDate>) -> Self {
+        AdditionalHolidays { base, extra }
+    }
+
+    /// Returns the base calendar.
+    #[must_use]
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    /// Returns the extra holidays.
+    #[must_use]
+    pub fn extra(&self) -> &HashSet<NaiveDate> {
+        &self.extra
+    }
+}
+
+impl<C: Business> Business for AdditionalHolidays<C> {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.extra.contains(&day) || self.base.is_holiday(day)
+    }
+
+    fn weekend_days(&self) -> &[Weekday] {
+        self.base.weekend_days()
+    }
+
+    fn is_weekend(&self, day: NaiveDate) -> bool {
+        self.base.is_weekend(day)
+    }
+
+    fn is_business(&self, day: NaiveDate) -> bool {
+        !self.extra.contains(&day) && self.base.is_business(day)
+    }
+
+    fn has_standard_business_days(&self) -> bool {
+        self.base.has_standard_business_days()
+    }
+}
+
+/// `OverrideCalendar` is a calendar forcing some dates, e.g. special trading days, to be business
+/// days regardless of the holidays and weekends of a base calendar.
+#[derive(Debug, Clone)]
+pub struct OverrideCalendar<C: Business> {
+    base: C,
+    force_business: HashSet<NaiveDate>,
+}
+
+impl<C: Business> OverrideCalendar<C> {
+    /// Creates a calendar treating the `force_business` dates as business days in the `base`
+    /// calendar.
+    #[must_use]
+    pub fn new(base: C, force_business: HashSet<NaiveDate>) -> Self {
+        OverrideCalendar {
+            base,
+            force_business,
+        }
+    }
+
+    /// Returns the base calendar.
+    #[must_use]
+    pub fn base(&self) -> &C {
+        &self.base
+    }
+
+    /// Returns the dates forced to be business days.
+    #[must_use]
+    pub fn force_business(&self) -> &HashSet<NaiveDate> {
+        &self.force_business
+    }
+}
+
+impl<C: Business> Business for OverrideCalendar<C> {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        !self.force_business.contains(&day) && self.base.is_holiday(day)
+    }
+
+    fn weekend_days(&self) -> &[Weekday] {
+        self.base.weekend_days()
+    }
+
+    fn is_weekend(&self, day: NaiveDate) -> bool {
+        self.base.is_weekend(day)
+    }
+
+    fn is_business(&self, day: NaiveDate) -> bool {
+        self.force_business.contains(&day) || self.base.is_business(day)
+    }
+
+    /// The dates forced to be business days may fall on weekends.
+    fn has_standard_business_days(&self) -> bool {
+        false
+    }
+}
+
+/// `CalendarExt` provides the layering of changes on top of any [`Business`] calendar.
+pub trait CalendarExt: Business + Sized {
+    /// Returns the calendar with the `extra` holidays added.
+    #[must_use]
+    fn with_additional_holidays(self, extra: HashSet<NaiveDate>) -> AdditionalHolidays<Self> {
+        AdditionalHolidays::new(self, extra)
+    }
+
+    /// Returns the calendar with the `force_business` dates treated as business days, even if
+    /// they are holidays or weekend days.
+    #[must_use]
+    fn remove_holidays(self, force_business: HashSet<NaiveDate>) -> OverrideCalendar<Self> {
+        OverrideCalendar::new(self, force_business)
+    }
+}
+
+impl<C: Business> CalendarExt for C {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::target::TargetCalendar;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn two_firm_holidays_on_target() {
+        // Fridays, March 15th and June 14th, 2024.
+        let firm = HashSet::from([date(2024, 3, 15), date(2024, 6, 14)]);
+        let calendar = TargetCalendar.with_additional_holidays(firm);
+
+        for day in [date(2024, 3, 15), date(2024, 6, 14)] {
+            assert!(TargetCalendar.is_business(day), "{day}");
+            assert!(calendar.is_holiday(day), "{day}");
+            assert!(!calendar.is_business(day), "{day}");
+        }
+        assert_eq!(
+            calendar.following(date(2024, 3, 15)),
+            Some(date(2024, 3, 18))
+        );
+        assert_eq!(
+            calendar.preceding(date(2024, 6, 16)),
+            Some(date(2024, 6, 13))
+        );
+
+        // The holidays of the base calendar are kept.
+        assert!(calendar.is_holiday(date(2024, 12, 25)));
+        assert!(calendar.is_business(date(2024, 3, 14)));
+        assert_eq!(calendar.extra().len(), 2);
+    }
+
+    #[test]
+    fn forced_business_day_stops_following() {
+        // Good Friday, March 29th, 2024, traded as a special business day.
+        let good_friday = date(2024, 3, 29);
+        let calendar = TargetCalendar.remove_holidays(HashSet::from([good_friday]));
+
+        assert!(TargetCalendar.is_holiday(good_friday));
+        assert!(!calendar.is_holiday(good_friday));
+        assert!(calendar.is_business(good_friday));
+        assert_eq!(
+            TargetCalendar.following(good_friday),
+            Some(date(2024, 4, 2))
+        );
+        assert_eq!(calendar.following(good_friday), Some(good_friday));
+
+        // Easter Monday is still a holiday, so the search backward stops on Good Friday.
+        assert_eq!(calendar.preceding(date(2024, 4, 1)), Some(good_friday));
+    }
+
+    #[test]
+    fn forced_weekend_business_day() {
+        // Saturday, March 30th, 2024.
+        let saturday = date(2024, 3, 30);
+        let calendar = TargetCalendar.remove_holidays(HashSet::from([saturday]));
+
+        assert!(calendar.is_weekend(saturday));
+        assert!(calendar.is_business(saturday));
+        assert_eq!(calendar.following(date(2024, 3, 29)), Some(saturday));
+        assert_eq!(calendar.preceding(date(2024, 4, 1)), Some(saturday));
+    }
+}
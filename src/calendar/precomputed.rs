@@ -0,0 +1,267 @@
+//! This module provides a calendar wrapper answering business day queries from a precomputed
+//! table.
+
+use std::ops::RangeInclusive;
+
+use chrono::{Days, NaiveDate, Weekday};
+
+use crate::{
+    calendar::{Business, CalendarExhausted, SearchDirection},
+    AccrualError,
+};
+
+/// `PrecomputedCalendar` wraps a calendar, materializing for a range of years a bitset of
+/// which days are business days.
+///
+/// Within the range, [`Business::is_business`] is a single lookup, and
+/// [`Business::try_following`] and [`Business::try_preceding`] (and so [`Business::following`]
+/// and [`Business::preceding`]) scan the bitset a word at a time. Dates outside the range are
+/// delegated to the wrapped calendar. This trades memory (about 46 bytes per year) for speed
+/// when doing a large number of adjustments over the same calendar.
+///
+/// There is no criterion benchmark, as criterion is not available to this build; an ignored
+/// test times the adjustments against the wrapped calendar instead (run it with
+/// `cargo test --release -- --ignored --nocapture`).
+pub struct PrecomputedCalendar<'a> {
+    calendar: &'a dyn Business,
+    start: NaiveDate,
+    days: usize,
+    business: Vec<u64>,
+}
+
+impl<'a> PrecomputedCalendar<'a> {
+    /// Precomputes the business days of the `calendar` from January 1st of the first year
+    /// to December 31st of the last year of the range.
+    ///
+    /// Returns `None` if the range is empty or out of the supported dates.
+    #[must_use]
+    pub fn new(calendar: &'a dyn Business, years: RangeInclusive<i32>) -> Option<Self> {
+        let start = NaiveDate::from_ymd_opt(*years.start(), 1, 1)?;
+        let end = NaiveDate::from_ymd_opt(*years.end(), 12, 31)?;
+        if start > end {
+            return None;
+        }
+
+        let days = usize::try_from((end - start).num_days() + 1).ok()?;
+        let mut business = vec![0_u64; days.div_ceil(64)];
+        for (index, day) in start.iter_days().take(days).enumerate() {
+            if calendar.is_business(day) {
+                business[index / 64] |= 1 << (index % 64);
+            }
+        }
+
+        Some(PrecomputedCalendar {
+            calendar,
+            start,
+            days,
+            business,
+        })
+    }
+
+    /// Returns the position of the date in the bitset, if it is within the precomputed range.
+    fn index(&self, day: NaiveDate) -> Option<usize> {
+        usize::try_from((day - self.start).num_days())
+            .ok()
+            .filter(|index| *index < self.days)
+    }
+
+    /// Returns the date at the position in the bitset.
+    fn date(&self, index: usize) -> Option<NaiveDate> {
+        self.start
+            .checked_add_days(Days::new(u64::try_from(index).ok()?))
+    }
+
+    /// Returns the position of the first business day at or after `index`.
+    fn next_set(&self, index: usize) -> Option<usize> {
+        let mut word = index / 64;
+        let mut bits = self.business[word] & (u64::MAX << (index % 64));
+        loop {
+            if bits != 0 {
+                let found = word * 64 + bits.trailing_zeros() as usize;
+                return (found < self.days).then_some(found);
+            }
+            word += 1;
+            bits = *self.business.get(word)?;
+        }
+    }
+
+    /// Returns the position of the last business day at or before `index`.
+    fn previous_set(&self, index: usize) -> Option<usize> {
+        let mut word = index / 64;
+        let mut bits = self.business[word] & (u64::MAX >> (63 - index % 64));
+        loop {
+            if bits != 0 {
+                return Some(word * 64 + 63 - bits.leading_zeros() as usize);
+            }
+            word = word.checked_sub(1)?;
+            bits = self.business[word];
+        }
+    }
+}
+
+impl Business for PrecomputedCalendar<'_> {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.calendar.is_holiday(day)
+    }
+
+    fn weekend_days(&self) -> &[Weekday] {
+        self.calendar.weekend_days()
+    }
+
+    fn is_weekend(&self, day: NaiveDate) -> bool {
+        self.calendar.is_weekend(day)
+    }
+
+    fn is_business(&self, day: NaiveDate) -> bool {
+        match self.index(day) {
+            Some(index) => self.business[index / 64] & (1 << (index % 64)) != 0,
+            None => self.calendar.is_business(day),
+        }
+    }
+
+    fn has_standard_business_days(&self) -> bool {
+        self.calendar.has_standard_business_days()
+    }
+
+    fn try_following(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        let Some(index) = self.index(day) else {
+            return self.calendar.try_following(day);
+        };
+
+        let found = match self.next_set(index) {
+            Some(found) => self.date(found),
+            None => self
+                .date(self.days)
+                .and_then(|after| self.calendar.following(after)),
+        };
+        found.ok_or(
+            CalendarExhausted {
+                from: day,
+                direction: SearchDirection::Forward,
+            }
+            .into(),
+        )
+    }
+
+    fn try_preceding(&self, day: NaiveDate) -> Result<NaiveDate, AccrualError> {
+        let Some(index) = self.index(day) else {
+            return self.calendar.try_preceding(day);
+        };
+
+        let found = match self.previous_set(index) {
+            Some(found) => self.date(found),
+            None => self
+                .start
+                .pred_opt()
+                .and_then(|before| self.calendar.preceding(before)),
+        };
+        found.ok_or(
+            CalendarExhausted {
+                from: day,
+                direction: SearchDirection::Backward,
+            }
+            .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use chrono::{Datelike, NaiveDate};
+
+    use super::*;
+    use crate::calendar::{target::TargetCalendar, weekend::AlwaysBusinessCalendar};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn agrees_with_target_from_1999_to_2032() {
+        let precomputed = PrecomputedCalendar::new(&TargetCalendar, 1999..=2032).unwrap();
+
+        // The range is checked one month either side, so the delegation is covered as well.
+        let mismatches = date(1998, 12, 1)
+            .iter_days()
+            .take_while(|day| *day < date(2033, 2, 1))
+            .filter(|day| {
+                precomputed.is_business(*day) != TargetCalendar.is_business(*day)
+                    || precomputed.following(*day) != TargetCalendar.following(*day)
+                    || precomputed.preceding(*day) != TargetCalendar.preceding(*day)
+                    || precomputed.try_following(*day) != TargetCalendar.try_following(*day)
+                    || precomputed.try_preceding(*day) != TargetCalendar.try_preceding(*day)
+            })
+            .count();
+
+        assert_eq!(mismatches, 0);
+    }
+
+    #[test]
+    fn searches_past_range_end() {
+        // December 31st, 2032 is a Friday; the next business day is outside the range.
+        let precomputed = PrecomputedCalendar::new(&TargetCalendar, 2032..=2032).unwrap();
+
+        assert_eq!(
+            precomputed.following(date(2032, 12, 25)),
+            Some(date(2032, 12, 27))
+        );
+        assert_eq!(
+            precomputed.following(date(2033, 1, 1)),
+            Some(date(2033, 1, 3))
+        );
+        assert_eq!(
+            precomputed.preceding(date(2032, 1, 1)),
+            Some(date(2031, 12, 31))
+        );
+    }
+
+    #[test]
+    fn new_rejects_empty_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 2030..=2029;
+
+        assert!(PrecomputedCalendar::new(&AlwaysBusinessCalendar, empty).is_none());
+        assert!(PrecomputedCalendar::new(&AlwaysBusinessCalendar, 2030..=2030).is_some());
+    }
+
+    /// Returns the time taken to adjust every day from 2000 to 2029 with the `following` and
+    /// `preceding` conventions `rounds` times, and the number of days adjusted onto a Monday.
+    fn time_adjustments(calendar: &dyn Business, rounds: usize) -> (Duration, usize) {
+        let days: Vec<NaiveDate> = date(2000, 1, 1)
+            .iter_days()
+            .take_while(|day| *day < date(2030, 1, 1))
+            .collect();
+
+        let started = Instant::now();
+        let mut mondays = 0;
+        for _ in 0..rounds {
+            for day in &days {
+                let following = calendar.following(*day).unwrap();
+                let preceding = calendar.preceding(*day).unwrap();
+                mondays += usize::from(following.weekday() == Weekday::Mon);
+                mondays += usize::from(preceding.weekday() == Weekday::Mon);
+            }
+        }
+
+        (started.elapsed(), mondays)
+    }
+
+    /// Prints the speedup over the wrapped calendar, without asserting it, as timings depend
+    /// on the machine.
+    #[test]
+    #[ignore = "timing comparison, run explicitly"]
+    fn timing_against_wrapped_calendar() {
+        let precomputed = PrecomputedCalendar::new(&TargetCalendar, 1999..=2030).unwrap();
+
+        let (wrapped, expected) = time_adjustments(&TargetCalendar, 20);
+        let (bitset, mondays) = time_adjustments(&precomputed, 20);
+        println!(
+            "TargetCalendar: {wrapped:?}, PrecomputedCalendar: {bitset:?} ({:.1}x)",
+            wrapped.as_secs_f64() / bitset.as_secs_f64()
+        );
+
+        assert_eq!(mondays, expected);
+    }
+}
@@ -0,0 +1,47 @@
+//! This module provides a calendar defined by an explicit set of holidays.
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::Business;
+
+/// `SetCalendar` is a bank holiday calendar whose holidays are given explicitly, e.g. loaded
+/// from an external holiday data provider.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetCalendar {
+    holidays: HashSet<NaiveDate>,
+}
+
+impl SetCalendar {
+    /// Creates a calendar with the supplied holidays.
+    #[must_use]
+    pub fn new(holidays: HashSet<NaiveDate>) -> Self {
+        SetCalendar { holidays }
+    }
+
+    /// Returns the holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = self
+            .holidays
+            .iter()
+            .filter(|day| day.year() == year)
+            .copied()
+            .collect();
+        holidays.sort_unstable();
+        holidays
+    }
+}
+
+impl FromIterator<NaiveDate> for SetCalendar {
+    fn from_iter<I: IntoIterator<Item = NaiveDate>>(iter: I) -> Self {
+        SetCalendar::new(iter.into_iter().collect())
+    }
+}
+
+impl Business for SetCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays.contains(&day)
+    }
+}
@@ -0,0 +1,271 @@
+//! This module provides the serializable selection of a built-in calendar.
+//!
+//! A [`CalendarSpec`] is serialized as a map tagged by its `type`, e.g. `{"type":"target"}`, with
+//! the holidays of a set calendar given in `ISO 8601`, e.g.
+//! `{"type":"set","holidays":["2024-12-24"]}`.
+
+use std::fmt;
+
+use chrono::NaiveDate;
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::calendar::{
+    germany::GermanyCalendar,
+    latin_america::{BrazilCalendar, MexicoCalendar},
+    nordic::{DenmarkCalendar, NorwayCalendar, SwedenCalendar},
+    set::SetCalendar,
+    switzerland::SwitzerlandCalendar,
+    target::TargetCalendar,
+    united_states::UnitedStatesCalendar,
+    weekend::{AlwaysBusinessCalendar, WeekendCalendar},
+    Business,
+};
+
+/// The tags of the calendar specifications.
+const TAGS: &[&str] = &[
+    "weekend",
+    "always_business",
+    "target",
+    "united_states",
+    "switzerland",
+    "germany",
+    "norway",
+    "sweden",
+    "denmark",
+    "mexico",
+    "brazil",
+    "set",
+];
+
+/// The fields of a serialized calendar specification.
+const FIELDS: &[&str] = &["type", "holidays"];
+
+/// A `CalendarSpec` represents the selection of a calendar, e.g. in a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CalendarSpec {
+    /// [`WeekendCalendar`].
+    Weekend,
+    /// [`AlwaysBusinessCalendar`].
+    AlwaysBusiness,
+    /// [`TargetCalendar`].
+    Target,
+    /// [`UnitedStatesCalendar`].
+    UnitedStates,
+    /// [`SwitzerlandCalendar`].
+    Switzerland,
+    /// [`GermanyCalendar`].
+    Germany,
+    /// [`NorwayCalendar`].
+    Norway,
+    /// [`SwedenCalendar`].
+    Sweden,
+    /// [`DenmarkCalendar`].
+    Denmark,
+    /// [`MexicoCalendar`].
+    Mexico,
+    /// [`BrazilCalendar`].
+    Brazil,
+    /// [`SetCalendar`] with the given holidays.
+    Set(Vec<NaiveDate>),
+}
+
+impl CalendarSpec {
+    /// Returns the tag of the specification.
+    #[must_use]
+    pub fn tag(&self) -> &'static str {
+        match self {
+            CalendarSpec::Weekend => "weekend",
+            CalendarSpec::AlwaysBusiness => "always_business",
+            CalendarSpec::Target => "target",
+            CalendarSpec::UnitedStates => "united_states",
+            CalendarSpec::Switzerland => "switzerland",
+            CalendarSpec::Germany => "germany",
+            CalendarSpec::Norway => "norway",
+            CalendarSpec::Sweden => "sweden",
+            CalendarSpec::Denmark => "denmark",
+            CalendarSpec::Mexico => "mexico",
+            CalendarSpec::Brazil => "brazil",
+            CalendarSpec::Set(_) => "set",
+        }
+    }
+
+    /// Creates the specified calendar.
+    #[must_use]
+    pub fn build(&self) -> Box<dyn Business> {
+        match self {
+            CalendarSpec::Weekend => Box::new(WeekendCalendar),
+            CalendarSpec::AlwaysBusiness => Box::new(AlwaysBusinessCalendar),
+            CalendarSpec::Target => Box::new(TargetCalendar),
+            CalendarSpec::UnitedStates => Box::new(UnitedStatesCalendar),
+            CalendarSpec::Switzerland => Box::new(SwitzerlandCalendar),
+            CalendarSpec::Germany => Box::new(GermanyCalendar),
+            CalendarSpec::Norway => Box::new(NorwayCalendar),
+            CalendarSpec::Sweden => Box::new(SwedenCalendar),
+            CalendarSpec::Denmark => Box::new(DenmarkCalendar),
+            CalendarSpec::Mexico => Box::new(MexicoCalendar),
+            CalendarSpec::Brazil => Box::new(BrazilCalendar),
+            CalendarSpec::Set(holidays) => {
+                Box::new(holidays.iter().copied().collect::<SetCalendar>())
+            }
+        }
+    }
+
+    /// Returns the specification of a calendar without holidays to supply, given its tag.
+    fn from_tag(tag: &str) -> Option<Self> {
+        let spec = match tag {
+            "weekend" => CalendarSpec::Weekend,
+            "always_business" => CalendarSpec::AlwaysBusiness,
+            "target" => CalendarSpec::Target,
+            "united_states" => CalendarSpec::UnitedStates,
+            "switzerland" => CalendarSpec::Switzerland,
+            "germany" => CalendarSpec::Germany,
+            "norway" => CalendarSpec::Norway,
+            "sweden" => CalendarSpec::Sweden,
+            "denmark" => CalendarSpec::Denmark,
+            "mexico" => CalendarSpec::Mexico,
+            "brazil" => CalendarSpec::Brazil,
+            _ => return None,
+        };
+
+        Some(spec)
+    }
+}
+
+impl From<CalendarSpec> for Box<dyn Business> {
+    fn from(spec: CalendarSpec) -> Self {
+        spec.build()
+    }
+}
+
+impl Serialize for CalendarSpec {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let holidays = match self {
+            CalendarSpec::Set(holidays) => Some(holidays),
+            _ => None,
+        };
+
+        let mut map = serializer.serialize_map(Some(1 + usize::from(holidays.is_some())))?;
+        map.serialize_entry("type", self.tag())?;
+        if let Some(holidays) = holidays {
+            map.serialize_entry("holidays", holidays)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for CalendarSpec {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(CalendarSpecVisitor)
+    }
+}
+
+/// Reads a [`CalendarSpec`] from a map tagged by its `type`.
+struct CalendarSpecVisitor;
+
+impl<'de> Visitor<'de> for CalendarSpecVisitor {
+    type Value = CalendarSpec;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a calendar specification tagged by its type")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut tag: Option<String> = None;
+        let mut holidays: Option<Vec<NaiveDate>> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "type" if tag.is_some() => return Err(de::Error::duplicate_field("type")),
+                "type" => tag = Some(map.next_value()?),
+                "holidays" if holidays.is_some() => {
+                    return Err(de::Error::duplicate_field("holidays"))
+                }
+                "holidays" => holidays = Some(map.next_value()?),
+                other => return Err(de::Error::unknown_field(other, FIELDS)),
+            }
+        }
+
+        let tag = tag.ok_or_else(|| de::Error::missing_field("type"))?;
+        match (tag.as_str(), holidays) {
+            ("set", Some(holidays)) => Ok(CalendarSpec::Set(holidays)),
+            ("set", None) => Err(de::Error::missing_field("holidays")),
+            (_, Some(_)) => Err(de::Error::unknown_field("holidays", &FIELDS[..1])),
+            (tag, None) => {
+                CalendarSpec::from_tag(tag).ok_or_else(|| de::Error::unknown_variant(tag, TAGS))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn target_round_trip() {
+        let json = serde_json::to_string(&CalendarSpec::Target).unwrap();
+        assert_eq!(json, r#"{"type":"target"}"#);
+
+        let spec: CalendarSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(spec, CalendarSpec::Target);
+
+        let calendar: Box<dyn Business> = spec.into();
+        assert!(calendar.is_holiday(date(2024, 12, 25)));
+    }
+
+    #[test]
+    fn set_calendar_round_trip() {
+        let spec = CalendarSpec::Set(vec![date(2024, 12, 24), date(2024, 12, 31)]);
+
+        let json = serde_json::to_string(&spec).unwrap();
+        assert_eq!(
+            json,
+            r#"{"type":"set","holidays":["2024-12-24","2024-12-31"]}"#
+        );
+
+        let parsed: CalendarSpec = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, spec);
+
+        let calendar = parsed.build();
+        assert!(calendar.is_holiday(date(2024, 12, 24)));
+        assert!(!calendar.is_holiday(date(2024, 12, 25)));
+        assert!(!calendar.is_business(date(2024, 12, 31)));
+    }
+
+    #[test]
+    fn every_tag_round_trips() {
+        for tag in TAGS.iter().filter(|tag| **tag != "set") {
+            let spec = CalendarSpec::from_tag(tag).unwrap();
+            let json = serde_json::to_string(&spec).unwrap();
+
+            assert_eq!(json, format!(r#"{{"type":"{tag}"}}"#));
+            assert_eq!(serde_json::from_str::<CalendarSpec>(&json).unwrap(), spec);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_specs() {
+        for json in [
+            r#"{"type":"mars"}"#,
+            r#"{"type":"set"}"#,
+            r#"{"type":"target","holidays":[]}"#,
+            r#"{"holidays":[]}"#,
+            r#"{"type":"target","type":"target"}"#,
+            r#"{"type":"target","region":"eu"}"#,
+        ] {
+            assert!(
+                serde_json::from_str::<CalendarSpec>(json).is_err(),
+                "{json}"
+            );
+        }
+    }
+}
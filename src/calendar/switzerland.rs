@@ -0,0 +1,79 @@
+//! This module provides the Swiss (Zurich) bank holiday calendar.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::{holidays::easter_offset, Business};
+
+/// `SwitzerlandCalendar` is the bank holiday calendar of Zurich, used for `CHF` settlement.
+///
+/// The holidays are New Year's Day, Berchtold's Day, Good Friday, Easter Monday, Labour Day,
+/// Ascension Day, Whit Monday, Swiss National Day, Christmas Day and St Stephen's Day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SwitzerlandCalendar;
+
+impl SwitzerlandCalendar {
+    /// Returns the bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 1, 2),
+            easter_offset(year, -2),
+            easter_offset(year, 1),
+            NaiveDate::from_ymd_opt(year, 5, 1),
+            easter_offset(year, 39),
+            easter_offset(year, 50),
+            NaiveDate::from_ymd_opt(year, 8, 1),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+            NaiveDate::from_ymd_opt(year, 12, 26),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Business for SwitzerlandCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn ascension_and_whit_monday_2024() {
+        // Easter Sunday 2024 is March 31st.
+        assert!(SwitzerlandCalendar.is_holiday(date(2024, 5, 9)));
+        assert!(SwitzerlandCalendar.is_holiday(date(2024, 5, 20)));
+        assert!(!SwitzerlandCalendar.is_holiday(date(2024, 5, 8)));
+        assert!(!SwitzerlandCalendar.is_holiday(date(2024, 5, 21)));
+    }
+
+    #[test]
+    fn holidays_in_2024() {
+        assert_eq!(
+            SwitzerlandCalendar.holidays_in_year(2024),
+            vec![
+                date(2024, 1, 1),
+                date(2024, 1, 2),
+                date(2024, 3, 29),
+                date(2024, 4, 1),
+                date(2024, 5, 1),
+                date(2024, 5, 9),
+                date(2024, 5, 20),
+                date(2024, 8, 1),
+                date(2024, 12, 25),
+                date(2024, 12, 26),
+            ]
+        );
+    }
+}
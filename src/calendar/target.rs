@@ -0,0 +1,100 @@
+//! This module provides the `TARGET` (Trans-European Automated Real-time Gross settlement
+//! Express Transfer system) calendar, also used for the `€STR` fixings.
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::calendar::{holidays::easter_offset, Business};
+
+/// `TargetCalendar` is the `TARGET2` closing day calendar, used for `EUR` settlement.
+///
+/// The closing days are New Year's Day, Good Friday, Easter Monday, Labour Day, Christmas Day
+/// and St Stephen's Day. Good Friday, Easter Monday, Labour Day and St Stephen's Day are
+/// closing days since 2000, and December 31st was a closing day in 1998, 1999 and 2001.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TargetCalendar;
+
+/// `EstrCalendar` is the calendar of the euro short-term rate (`€STR`) fixings.
+///
+/// The `ECB` publishes `€STR` on every `TARGET2` business day, for the rate of the previous
+/// `TARGET2` business day, so the fixing calendar is the [`TargetCalendar`] itself. The
+/// publication lag is not part of the calendar, it is applied as a one business day shift
+/// (see [`Business::add_business_days`]).
+pub type EstrCalendar = TargetCalendar;
+
+impl TargetCalendar {
+    /// Returns the closing days in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let since_2000 = year >= 2000;
+        [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            easter_offset(year, -2).filter(|_| since_2000),
+            easter_offset(year, 1).filter(|_| since_2000),
+            NaiveDate::from_ymd_opt(year, 5, 1).filter(|_| since_2000),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+            NaiveDate::from_ymd_opt(year, 12, 26).filter(|_| since_2000),
+            NaiveDate::from_ymd_opt(year, 12, 31).filter(|_| matches!(year, 1998 | 1999 | 2001)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Business for TargetCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::BusinessDayConvetion;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn estr_business_days_match_target_in_2024() {
+        let estr = EstrCalendar::default();
+        for day in date(2024, 1, 1)
+            .iter_days()
+            .take_while(|day| day.year() == 2024)
+        {
+            assert_eq!(
+                estr.is_business(day),
+                TargetCalendar.is_business(day),
+                "{day}"
+            );
+        }
+
+        assert_eq!(
+            estr.holidays_in_year(2024),
+            vec![
+                date(2024, 1, 1),
+                date(2024, 3, 29),
+                date(2024, 4, 1),
+                date(2024, 5, 1),
+                date(2024, 12, 25),
+                date(2024, 12, 26),
+            ]
+        );
+        assert_eq!(
+            estr.count_business_days(date(2024, 1, 1), date(2025, 1, 1)),
+            Some(256)
+        );
+    }
+
+    #[test]
+    fn closing_days_before_2002() {
+        assert_eq!(
+            TargetCalendar.adjust(date(2001, 12, 31), &BusinessDayConvetion::Following),
+            Some(date(2002, 1, 2))
+        );
+        // Good Friday became a closing day in 2000.
+        assert!(TargetCalendar.is_business(date(1999, 4, 2)));
+        assert!(!TargetCalendar.is_business(date(2000, 4, 21)));
+    }
+}
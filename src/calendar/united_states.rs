@@ -0,0 +1,195 @@
+//! This module provides the United States (New York) bank holiday calendar.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::calendar::{
+    holidays::{easter_offset, weekday_on_or_after},
+    Business,
+};
+
+/// `UnitedStatesCalendar` is the United States government bond settlement calendar, used for
+/// `USD` settlement.
+///
+/// The holidays are New Year's Day, Martin Luther King Jr. Day, Washington's Birthday, Good
+/// Friday, Memorial Day, Juneteenth (since 2022), Independence Day, Labor Day, Columbus Day,
+/// Veterans Day, Thanksgiving Day and Christmas Day. A fixed-date holiday falling on a Saturday
+/// is observed on the preceding Friday and one falling on a Sunday on the following Monday,
+/// except New Year's Day, which is not moved into the previous year, and Veterans Day, which is
+/// not moved from a Saturday.
+///
+/// Since 1996, Good Friday is only an early close, not a holiday, when it is the first Friday of
+/// April, the release date of the nonfarm payrolls (e.g. April 2nd, 2021).
+///
+/// This follows the `UnitedStates(GovernmentBond)` calendar of `QuantLib`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnitedStatesCalendar;
+
+impl UnitedStatesCalendar {
+    /// Returns the observed bank holidays in the given year, in chronological order.
+    #[must_use]
+    pub fn holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = Self::fixed_holidays(year)
+            .into_iter()
+            .flatten()
+            .filter(|day| !is_saturday_veterans_day(*day))
+            .filter_map(observed)
+            .filter(|day| day.year() == year)
+            .chain(Self::weekday_holidays(year).into_iter().flatten())
+            .chain(Self::good_friday(year))
+            .collect();
+        holidays.sort_unstable();
+        holidays
+    }
+
+    /// Returns the actual dates of the bank holidays in the given year, before moving the ones
+    /// falling on a weekend to their observed dates, in chronological order.
+    ///
+    /// E.g. Independence Day 2021 is July 4th (a Sunday), while it is observed on July 5th.
+    #[must_use]
+    pub fn actual_holidays_in_year(&self, year: i32) -> Vec<NaiveDate> {
+        let mut holidays: Vec<NaiveDate> = Self::fixed_holidays(year)
+            .into_iter()
+            .chain(Self::weekday_holidays(year))
+            .flatten()
+            .chain(Self::good_friday(year))
+            .collect();
+        holidays.sort_unstable();
+        holidays
+    }
+
+    /// Returns the holidays falling on a fixed date: New Year's Day, Juneteenth, Independence
+    /// Day, Veterans Day and Christmas Day.
+    fn fixed_holidays(year: i32) -> [Option<NaiveDate>; 5] {
+        let juneteenth = if year >= 2022 {
+            NaiveDate::from_ymd_opt(year, 6, 19)
+        } else {
+            None
+        };
+
+        [
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            juneteenth,
+            NaiveDate::from_ymd_opt(year, 7, 4),
+            NaiveDate::from_ymd_opt(year, 11, 11),
+            NaiveDate::from_ymd_opt(year, 12, 25),
+        ]
+    }
+
+    /// Returns Good Friday, unless it is an early close for the release of the nonfarm
+    /// payrolls on the first Friday of April (since 1996).
+    fn good_friday(year: i32) -> Option<NaiveDate> {
+        easter_offset(year, -2).filter(|day| year < 1996 || day.day() > 7)
+    }
+
+    /// Returns the holidays falling on a given weekday of a month: Martin Luther King Jr. Day,
+    /// Washington's Birthday, Memorial Day, Labor Day, Columbus Day and Thanksgiving Day.
+    fn weekday_holidays(year: i32) -> [Option<NaiveDate>; 6] {
+        [
+            NaiveDate::from_weekday_of_month_opt(year, 1, Weekday::Mon, 3),
+            NaiveDate::from_weekday_of_month_opt(year, 2, Weekday::Mon, 3),
+            NaiveDate::from_ymd_opt(year, 5, 25)
+                .and_then(|day| weekday_on_or_after(day, Weekday::Mon)),
+            NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Mon, 1),
+            NaiveDate::from_weekday_of_month_opt(year, 10, Weekday::Mon, 2),
+            NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Thu, 4),
+        ]
+    }
+}
+
+impl Business for UnitedStatesCalendar {
+    fn is_holiday(&self, day: NaiveDate) -> bool {
+        self.holidays_in_year(day.year()).contains(&day)
+    }
+}
+
+/// Checks whether the date is Veterans Day falling on a Saturday, which is not observed on the
+/// preceding Friday.
+fn is_saturday_veterans_day(day: NaiveDate) -> bool {
+    (day.month(), day.day()) == (11, 11) && day.weekday() == Weekday::Sat
+}
+
+/// Moves a holiday falling on a Saturday to the preceding Friday and one falling on a Sunday to
+/// the following Monday.
+fn observed(day: NaiveDate) -> Option<NaiveDate> {
+    match day.weekday() {
+        Weekday::Sat => day.checked_sub_signed(Duration::days(1)),
+        Weekday::Sun => day.checked_add_signed(Duration::days(1)),
+        _ => Some(day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn independence_day_2021_actual_and_observed() {
+        let calendar = UnitedStatesCalendar;
+
+        assert!(calendar
+            .actual_holidays_in_year(2021)
+            .contains(&date(2021, 7, 4)));
+        assert!(!calendar
+            .actual_holidays_in_year(2021)
+            .contains(&date(2021, 7, 5)));
+        assert!(calendar.holidays_in_year(2021).contains(&date(2021, 7, 5)));
+        assert!(!calendar.holidays_in_year(2021).contains(&date(2021, 7, 4)));
+        assert!(calendar.is_holiday(date(2021, 7, 5)));
+    }
+
+    #[test]
+    fn holidays_2021() {
+        // Christmas Day is a Saturday, observed on Friday, December 24th, while New Year's Day
+        // 2022, also a Saturday, is not moved into 2021.
+        assert_eq!(
+            UnitedStatesCalendar.holidays_in_year(2021),
+            [
+                date(2021, 1, 1),
+                date(2021, 1, 18),
+                date(2021, 2, 15),
+                date(2021, 5, 31),
+                date(2021, 7, 5),
+                date(2021, 9, 6),
+                date(2021, 10, 11),
+                date(2021, 11, 11),
+                date(2021, 11, 25),
+                date(2021, 12, 24),
+            ]
+        );
+        assert_eq!(UnitedStatesCalendar.actual_holidays_in_year(2021).len(), 10);
+        assert!(!UnitedStatesCalendar.is_holiday(date(2021, 12, 31)));
+    }
+
+    #[test]
+    fn good_friday_except_on_nonfarm_payrolls() {
+        let calendar = UnitedStatesCalendar;
+
+        assert!(calendar.is_holiday(date(2025, 4, 18)));
+        assert!(calendar.is_holiday(date(2024, 3, 29)));
+        // The first Fridays of April, on which the nonfarm payrolls are released.
+        assert!(!calendar.is_holiday(date(2021, 4, 2)));
+        assert!(!calendar.is_holiday(date(2023, 4, 7)));
+        assert!(calendar.is_holiday(date(1994, 4, 1)));
+        assert!(!calendar.is_holiday(date(2025, 4, 21)));
+    }
+
+    #[test]
+    fn veterans_day_not_moved_from_saturday() {
+        let calendar = UnitedStatesCalendar;
+
+        assert!(!calendar.is_holiday(date(2023, 11, 10)));
+        assert!(!calendar
+            .holidays_in_year(2023)
+            .contains(&date(2023, 11, 11)));
+        assert!(calendar
+            .actual_holidays_in_year(2023)
+            .contains(&date(2023, 11, 11)));
+        assert!(calendar.is_holiday(date(2029, 11, 12)));
+        // Other fixed-date holidays are still moved from a Saturday to the Friday.
+        assert!(calendar.is_holiday(date(2020, 7, 3)));
+    }
+}
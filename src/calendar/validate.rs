@@ -0,0 +1,123 @@
+//! This module provides a self-consistency check for the authors of custom calendars.
+
+use chrono::NaiveDate;
+
+use crate::calendar::Business;
+
+/// The number of consecutive non-business days flagged as [`CalendarAnomaly::NoBusinessDay`].
+const WEEK: u32 = 7;
+
+/// A `CalendarAnomaly` represents a suspicious definition found in a calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarAnomaly {
+    /// The date is a holiday falling on a weekend, which is redundant.
+    WeekendHoliday(NaiveDate),
+    /// There is no business day in the week starting on the date.
+    NoBusinessDay(NaiveDate),
+    /// [`Business::is_business`] disagrees with [`Business::is_holiday`] and
+    /// [`Business::is_weekend`] on the date. This is expected for calendars with working
+    /// weekend days (e.g. [`ChinaCalendar`](crate::calendar::china::ChinaCalendar)).
+    InconsistentBusinessDay(NaiveDate),
+}
+
+/// Scans the calendar from `start` (inclusive) to `end` (exclusive) for anomalies, returning
+/// them in chronological order.
+///
+/// A run of non-business days is reported once, on its first day, as soon as it lasts a week.
+#[must_use]
+pub fn validate_calendar(
+    calendar: &dyn Business,
+    start: NaiveDate,
+    end: NaiveDate,
+) -> Vec<CalendarAnomaly> {
+    let mut anomalies = Vec::new();
+    let mut run: Option<(NaiveDate, u32)> = None;
+
+    for day in start.iter_days().take_while(|day| *day < end) {
+        let holiday = calendar.is_holiday(day);
+        let weekend = calendar.is_weekend(day);
+        let business = calendar.is_business(day);
+
+        if holiday && weekend {
+            anomalies.push(CalendarAnomaly::WeekendHoliday(day));
+        }
+        if business == (holiday || weekend) {
+            anomalies.push(CalendarAnomaly::InconsistentBusinessDay(day));
+        }
+
+        run = if business {
+            None
+        } else {
+            let (first, length) = run.map_or((day, 1), |(first, length)| (first, length + 1));
+            if length == WEEK {
+                anomalies.push(CalendarAnomaly::NoBusinessDay(first));
+            }
+            Some((first, length))
+        };
+    }
+
+    anomalies
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::{target::TargetCalendar, weekend::WeekendCalendar};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A calendar defined by a closure, used to build deliberately broken calendars.
+    struct ClosureCalendar<H: Fn(NaiveDate) -> bool, B: Fn(NaiveDate) -> bool> {
+        holiday: H,
+        business: B,
+    }
+
+    impl<H: Fn(NaiveDate) -> bool, B: Fn(NaiveDate) -> bool> Business for ClosureCalendar<H, B> {
+        fn is_holiday(&self, day: NaiveDate) -> bool {
+            (self.holiday)(day)
+        }
+
+        fn is_business(&self, day: NaiveDate) -> bool {
+            (self.business)(day)
+        }
+    }
+
+    #[test]
+    fn flags_broken_calendar() {
+        // A holiday on Saturday, March 2nd, a whole week of holidays from Monday, March 11th,
+        // and `is_business` claiming Sunday, March 24th, 2024 is a business day.
+        let holiday = |day: NaiveDate| {
+            day == date(2024, 3, 2) || (date(2024, 3, 11)..=date(2024, 3, 15)).contains(&day)
+        };
+        let calendar = ClosureCalendar {
+            holiday,
+            business: |day: NaiveDate| {
+                day == date(2024, 3, 24) || (!holiday(day) && !WeekendCalendar.is_weekend(day))
+            },
+        };
+
+        assert_eq!(
+            validate_calendar(&calendar, date(2024, 3, 1), date(2024, 4, 1)),
+            [
+                CalendarAnomaly::WeekendHoliday(date(2024, 3, 2)),
+                CalendarAnomaly::NoBusinessDay(date(2024, 3, 9)),
+                CalendarAnomaly::InconsistentBusinessDay(date(2024, 3, 24)),
+            ]
+        );
+    }
+
+    #[test]
+    fn target_is_consistent() {
+        // Christmas Day, 2022 falls on a Sunday, and TARGET does not move its holidays.
+        assert_eq!(
+            validate_calendar(&TargetCalendar, date(2024, 1, 1), date(2025, 1, 1)),
+            []
+        );
+        assert_eq!(
+            validate_calendar(&TargetCalendar, date(2022, 12, 1), date(2023, 1, 1)),
+            [CalendarAnomaly::WeekendHoliday(date(2022, 12, 25))]
+        );
+    }
+}
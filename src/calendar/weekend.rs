@@ -0,0 +1,82 @@
+//! This module provides calendars with no holidays.
+
+use chrono::{NaiveDate, Weekday};
+
+use crate::calendar::Business;
+
+/// `WeekendCalendar` is a calendar whose only non-business days are Saturdays and Sundays.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WeekendCalendar;
+
+impl Business for WeekendCalendar {
+    fn is_holiday(&self, _day: NaiveDate) -> bool {
+        false
+    }
+}
+
+/// `AlwaysBusinessCalendar` is a calendar with no non-business days at all, not even weekends,
+/// e.g. for instruments accruing on every calendar day. Every date adjustment leaves the date
+/// unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlwaysBusinessCalendar;
+
+impl Business for AlwaysBusinessCalendar {
+    fn is_holiday(&self, _day: NaiveDate) -> bool {
+        false
+    }
+
+    fn weekend_days(&self) -> &[Weekday] {
+        &[]
+    }
+
+    fn is_weekend(&self, _day: NaiveDate) -> bool {
+        false
+    }
+
+    fn is_business(&self, _day: NaiveDate) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BusinessDayConvetion;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn always_business_adjustments_are_identity() {
+        // A week from Wednesday to Tuesday, Christmas Day and the ends of the date range.
+        let days = date(2024, 3, 13).iter_days().take(7).chain([
+            date(2024, 12, 25),
+            NaiveDate::MIN,
+            NaiveDate::MAX,
+        ]);
+
+        for day in days {
+            assert!(AlwaysBusinessCalendar.is_business(day), "{day}");
+            assert_eq!(AlwaysBusinessCalendar.following(day), Some(day), "{day}");
+            assert_eq!(AlwaysBusinessCalendar.preceding(day), Some(day), "{day}");
+            assert_eq!(
+                AlwaysBusinessCalendar.adjust(day, &BusinessDayConvetion::ModifiedFollowiing),
+                Some(day),
+                "{day}"
+            );
+        }
+    }
+
+    #[test]
+    fn weekend_calendar_skips_only_weekends() {
+        assert_eq!(
+            WeekendCalendar.following(date(2024, 3, 16)),
+            Some(date(2024, 3, 18))
+        );
+        assert_eq!(
+            WeekendCalendar.following(date(2024, 12, 25)),
+            Some(date(2024, 12, 25))
+        );
+    }
+}
@@ -0,0 +1,245 @@
+//! The `conventions` module provides the standard market conventions of currencies.
+
+use std::{error::Error, fmt};
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::{fixed_income::day_count_fraction::DayCountConvention, BusinessDayConvetion};
+
+/// A `FinancialCenter` identifies the holiday calendar used for a currency's settlement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FinancialCenter {
+    /// New York, used for `USD`.
+    NewYork,
+    /// The `TARGET` system, used for `EUR`.
+    Target,
+    /// London, used for `GBP`.
+    London,
+    /// Tokyo, used for `JPY`.
+    Tokyo,
+    /// Zurich, used for `CHF`.
+    Zurich,
+    /// Toronto, used for `CAD`.
+    Toronto,
+    /// Sydney, used for `AUD`.
+    Sydney,
+}
+
+/// `Conventions` represent the standard conventions of overnight index swaps in a currency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Conventions {
+    day_count: DayCountConvention,
+    convention: BusinessDayConvetion,
+    spot_lag: i64,
+    calendar: FinancialCenter,
+}
+
+impl Conventions {
+    /// Returns the day count convention of the accrual periods.
+    #[must_use]
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    /// Returns the business day convention used to adjust the schedule dates.
+    #[must_use]
+    pub fn convention(&self) -> BusinessDayConvetion {
+        self.convention
+    }
+
+    /// Returns the number of business days between the trade date and the spot date.
+    #[must_use]
+    pub fn spot_lag(&self) -> i64 {
+        self.spot_lag
+    }
+
+    /// Returns the financial center whose holiday calendar is used.
+    #[must_use]
+    pub fn calendar(&self) -> FinancialCenter {
+        self.calendar
+    }
+}
+
+/// An `UnknownCurrency` error is returned if no conventions are defined for a currency code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownCurrency(pub String);
+
+impl fmt::Display for UnknownCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no conventions defined for currency {}", self.0)
+    }
+}
+
+impl Error for UnknownCurrency {}
+
+impl TryFrom<&str> for Conventions {
+    type Error = UnknownCurrency;
+
+    /// Returns the standard overnight index swap conventions of the currency with the given
+    /// `ISO 4217` code: `USD` (SOFR), `EUR` (€STR), `GBP` (SONIA), `JPY` (TONA), `CHF` (SARON),
+    /// `CAD` (CORRA) or `AUD` (AONIA).
+    fn try_from(code: &str) -> Result<Self, Self::Error> {
+        let (day_count, spot_lag, calendar) = match code {
+            "USD" => (DayCountConvention::Actual360, 2, FinancialCenter::NewYork),
+            "EUR" => (DayCountConvention::Actual360, 2, FinancialCenter::Target),
+            "GBP" => (
+                DayCountConvention::Actual365Fixed,
+                0,
+                FinancialCenter::London,
+            ),
+            "JPY" => (
+                DayCountConvention::Actual365Fixed,
+                2,
+                FinancialCenter::Tokyo,
+            ),
+            "CHF" => (DayCountConvention::Actual360, 2, FinancialCenter::Zurich),
+            "CAD" => (
+                DayCountConvention::Actual365Fixed,
+                1,
+                FinancialCenter::Toronto,
+            ),
+            "AUD" => (
+                DayCountConvention::Actual365Fixed,
+                1,
+                FinancialCenter::Sydney,
+            ),
+            _ => return Err(UnknownCurrency(code.to_string())),
+        };
+
+        Ok(Conventions {
+            day_count,
+            convention: BusinessDayConvetion::ModifiedFollowiing,
+            spot_lag,
+            calendar,
+        })
+    }
+}
+
+/// Returns the number of decimal places of the minor unit of the currency with the given
+/// `ISO 4217` code, e.g. `2` for `USD` (cents), `0` for `JPY` and `3` for `KWD`.
+///
+/// Currencies not known to have a different minor unit are assumed to have 2 decimal places.
+#[must_use]
+pub fn minor_units(currency: &str) -> u32 {
+    match currency {
+        "BIF" | "CLP" | "DJF" | "GNF" | "ISK" | "JPY" | "KMF" | "KRW" | "PYG" | "RWF" | "UGX"
+        | "UYI" | "VND" | "VUV" | "XAF" | "XOF" | "XPF" => 0,
+        "BHD" | "IQD" | "JOD" | "KWD" | "LYD" | "OMR" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// Rounds the cash `amount` to the minor unit of the currency with the given `ISO 4217` code
+/// (see [`minor_units`]), rounding half away from zero, e.g. to whole yen for `JPY`.
+#[must_use]
+pub fn currency_rounding(amount: Decimal, currency: &str) -> Decimal {
+    amount.round_dp_with_strategy(
+        minor_units(currency),
+        RoundingStrategy::MidpointAwayFromZero,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn conventions_of_supported_currencies() {
+        let expected = [
+            (
+                "USD",
+                DayCountConvention::Actual360,
+                2,
+                FinancialCenter::NewYork,
+            ),
+            (
+                "EUR",
+                DayCountConvention::Actual360,
+                2,
+                FinancialCenter::Target,
+            ),
+            (
+                "GBP",
+                DayCountConvention::Actual365Fixed,
+                0,
+                FinancialCenter::London,
+            ),
+            (
+                "JPY",
+                DayCountConvention::Actual365Fixed,
+                2,
+                FinancialCenter::Tokyo,
+            ),
+            (
+                "CHF",
+                DayCountConvention::Actual360,
+                2,
+                FinancialCenter::Zurich,
+            ),
+            (
+                "CAD",
+                DayCountConvention::Actual365Fixed,
+                1,
+                FinancialCenter::Toronto,
+            ),
+            (
+                "AUD",
+                DayCountConvention::Actual365Fixed,
+                1,
+                FinancialCenter::Sydney,
+            ),
+        ];
+
+        for (code, day_count, spot_lag, calendar) in expected {
+            let conventions = Conventions::try_from(code).unwrap();
+
+            assert_eq!(conventions.day_count(), day_count, "{code}");
+            assert_eq!(conventions.spot_lag(), spot_lag, "{code}");
+            assert_eq!(conventions.calendar(), calendar, "{code}");
+            assert_eq!(
+                conventions.convention(),
+                BusinessDayConvetion::ModifiedFollowiing,
+                "{code}"
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_currency_is_an_error() {
+        assert_eq!(
+            Conventions::try_from("XYZ"),
+            Err(UnknownCurrency("XYZ".to_string()))
+        );
+        assert_eq!(
+            Conventions::try_from("usd"),
+            Err(UnknownCurrency("usd".to_string()))
+        );
+        assert_eq!(
+            UnknownCurrency("XYZ".to_string()).to_string(),
+            "no conventions defined for currency XYZ"
+        );
+    }
+
+    #[test]
+    fn currency_rounding_to_minor_units() {
+        assert_eq!(currency_rounding(dec!(621833.5), "JPY"), dec!(621834));
+        assert_eq!(currency_rounding(dec!(621833.49), "JPY"), dec!(621833));
+        assert_eq!(currency_rounding(dec!(20222.225), "USD"), dec!(20222.23));
+        assert_eq!(currency_rounding(dec!(-20222.225), "USD"), dec!(-20222.23));
+        assert_eq!(currency_rounding(dec!(1.2345), "KWD"), dec!(1.235));
+        assert_eq!(currency_rounding(dec!(1.2345), "XYZ"), dec!(1.23));
+    }
+
+    #[test]
+    fn minor_units_of_currencies() {
+        assert_eq!(minor_units("USD"), 2);
+        assert_eq!(minor_units("EUR"), 2);
+        assert_eq!(minor_units("JPY"), 0);
+        assert_eq!(minor_units("KRW"), 0);
+        assert_eq!(minor_units("BHD"), 3);
+        assert_eq!(minor_units("jpy"), 2);
+    }
+}
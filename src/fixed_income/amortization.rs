@@ -0,0 +1,123 @@
+//! This module provides the notional amortization schedules.
+
+use rust_decimal::Decimal;
+
+/// An `AmortizationSchedule` represents how the outstanding notional of an instrument
+/// declines over its calculation periods.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AmortizationSchedule {
+    /// The full notional is outstanding in every period and repaid at maturity.
+    Bullet,
+    /// An equal part of the notional is repaid at the end of every period, so that nothing is
+    /// outstanding after the final period.
+    Linear,
+    /// The outstanding notional in each period is given explicitly, with the remaining notional
+    /// repaid at maturity.
+    Custom(Vec<Decimal>),
+}
+
+impl AmortizationSchedule {
+    /// Returns the outstanding notional in each of the `periods` periods, starting from the
+    /// `initial` notional.
+    ///
+    /// Returns `None` if there are no periods, or the number of custom notionals does not match
+    /// the number of periods.
+    #[must_use]
+    pub fn notionals(&self, initial: Decimal, periods: usize) -> Option<Vec<Decimal>> {
+        if periods == 0 {
+            return None;
+        }
+
+        match self {
+            AmortizationSchedule::Bullet => Some(vec![initial; periods]),
+            AmortizationSchedule::Linear => {
+                let count = Decimal::from(periods);
+                Some(
+                    (0..periods)
+                        .map(|period| initial * (count - Decimal::from(period)) / count)
+                        .collect(),
+                )
+            }
+            AmortizationSchedule::Custom(notionals) => {
+                if notionals.len() == periods {
+                    Some(notionals.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Returns the principal repaid at the end of each of the `periods` periods, starting from
+    /// the `initial` notional. The repayments add up to the notional outstanding in the first
+    /// period.
+    ///
+    /// Returns `None` under the same conditions as [`AmortizationSchedule::notionals`].
+    #[must_use]
+    pub fn repayments(&self, initial: Decimal, periods: usize) -> Option<Vec<Decimal>> {
+        let notionals = self.notionals(initial, periods)?;
+
+        Some(
+            notionals
+                .iter()
+                .zip(notionals.iter().skip(1).chain([&Decimal::ZERO]))
+                .map(|(outstanding, next)| outstanding - next)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn linear_four_periods() {
+        let schedule = AmortizationSchedule::Linear;
+
+        assert_eq!(
+            schedule.notionals(dec!(1000), 4),
+            Some(vec![dec!(1000), dec!(750), dec!(500), dec!(250)])
+        );
+        assert_eq!(
+            schedule.repayments(dec!(1000), 4),
+            Some(vec![dec!(250), dec!(250), dec!(250), dec!(250)])
+        );
+    }
+
+    #[test]
+    fn linear_final_principal_is_zero() {
+        let schedule = AmortizationSchedule::Linear;
+        let notionals = schedule.notionals(dec!(1000), 4).unwrap();
+        let repayments = schedule.repayments(dec!(1000), 4).unwrap();
+
+        let outstanding = notionals.last().unwrap() - repayments.last().unwrap();
+        assert_eq!(outstanding, Decimal::ZERO);
+        assert_eq!(repayments.iter().sum::<Decimal>(), dec!(1000));
+    }
+
+    #[test]
+    fn bullet_repays_at_maturity() {
+        let schedule = AmortizationSchedule::Bullet;
+
+        assert_eq!(schedule.notionals(dec!(1000), 3), Some(vec![dec!(1000); 3]));
+        assert_eq!(
+            schedule.repayments(dec!(1000), 3),
+            Some(vec![dec!(0), dec!(0), dec!(1000)])
+        );
+    }
+
+    #[test]
+    fn custom_must_match_periods() {
+        let schedule = AmortizationSchedule::Custom(vec![dec!(1000), dec!(400)]);
+
+        assert_eq!(
+            schedule.repayments(dec!(1000), 2),
+            Some(vec![dec!(600), dec!(400)])
+        );
+        assert_eq!(schedule.notionals(dec!(1000), 3), None);
+        assert_eq!(AmortizationSchedule::Bullet.notionals(dec!(1000), 0), None);
+    }
+}
@@ -0,0 +1,710 @@
+//! This module provides the fixed rate bond type.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+
+use crate::{
+    calendar::Business,
+    fixed_income::{
+        calculation_schedule::{CouponPeriod, Frequency, Generation, Schedule, ScheduleBuilder},
+        compounding::compound_discount_factor,
+        day_count_fraction::{act_act_isma, bus_252, DayCountConvention},
+    },
+    tenor::Tenor,
+    BusinessDayConvetion,
+};
+
+/// A `FixedRateBond` represents a bond paying a fixed coupon rate on its face value
+/// from the issue date until maturity.
+#[derive(Debug, Clone)]
+pub struct FixedRateBond {
+    issue: NaiveDate,
+    maturity: NaiveDate,
+    coupon: Decimal,
+    frequency: Frequency,
+    day_count: DayCountConvention,
+    convention: BusinessDayConvetion,
+    face_value: Decimal,
+    ex_dividend_days: u32,
+    call_schedule: Vec<(NaiveDate, Decimal)>,
+}
+
+/// The bracket of annual yields searched by [`FixedRateBond::yield_from_price`].
+const YIELD_BRACKET: (Decimal, Decimal) = (dec!(-0.5), dec!(1));
+
+/// The width of the yield bracket below which the yield search stops.
+const YIELD_TOLERANCE: Decimal = dec!(0.0000000001);
+
+impl FixedRateBond {
+    /// Creates a bond paying the annual `coupon` rate (e.g. `0.04` for 4%) with the given
+    /// frequency and day count convention.
+    ///
+    /// The bond has a face value of 100, its dates are not adjusted and it does not trade
+    /// ex-dividend.
+    #[must_use]
+    pub fn new(
+        issue: NaiveDate,
+        maturity: NaiveDate,
+        coupon: Decimal,
+        frequency: Frequency,
+        day_count: DayCountConvention,
+    ) -> Self {
+        FixedRateBond {
+            issue,
+            maturity,
+            coupon,
+            frequency,
+            day_count,
+            convention: BusinessDayConvetion::NoAdjustment,
+            face_value: dec!(100),
+            ex_dividend_days: 0,
+            call_schedule: Vec::new(),
+        }
+    }
+
+    /// Sets the business day convention used to adjust the coupon dates.
+    #[must_use]
+    pub fn convention(mut self, convention: BusinessDayConvetion) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Sets the face value of the bond.
+    #[must_use]
+    pub fn face_value(mut self, face_value: Decimal) -> Self {
+        self.face_value = face_value;
+        self
+    }
+
+    /// Sets the number of business days before each coupon date on which the bond starts
+    /// trading ex-dividend. Zero means the bond never trades ex-dividend.
+    #[must_use]
+    pub fn ex_dividend_days(mut self, days: u32) -> Self {
+        self.ex_dividend_days = days;
+        self
+    }
+
+    /// Sets the dates on which the issuer may redeem the bond early, together with the
+    /// redemption price of each call in the units of the face value (e.g. `100` for a call at
+    /// par). The call dates are expected to be coupon dates.
+    #[must_use]
+    pub fn call_schedule(mut self, call_schedule: Vec<(NaiveDate, Decimal)>) -> Self {
+        self.call_schedule = call_schedule;
+        self
+    }
+
+    /// Generates the coupon schedule of the bond backward from maturity, so that any stub
+    /// period is at the start, adjusting its dates using the `calendar`.
+    #[must_use]
+    pub fn schedule(&self, calendar: &dyn Business) -> Option<Schedule> {
+        ScheduleBuilder::new(self.issue, self.maturity, self.frequency)
+            .convention(self.convention)
+            .generation(Generation::Backward)
+            .build(calendar)
+    }
+
+    /// Calculates the interest accrued on the face value from the start of the current coupon
+    /// period to the `settlement` date.
+    ///
+    /// If the settlement date falls in the ex-dividend period, i.e. on or after the date
+    /// `ex_dividend_days` business days before the coupon is paid, the buyer does not receive
+    /// the coupon, and the accrued interest is negative: the interest from the settlement date
+    /// to the end of the period.
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life.
+    #[must_use]
+    pub fn accrued_interest(
+        &self,
+        settlement: NaiveDate,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        let period = self.period_on(settlement, calendar)?;
+
+        if self.is_ex_dividend(settlement, &period, calendar)? {
+            let fraction = self.fraction(settlement, period.accrual_end(), &period, calendar)?;
+            return Some(-self.face_value * self.coupon * fraction);
+        }
+
+        let fraction = self.fraction(period.accrual_start(), settlement, &period, calendar)?;
+        Some(self.face_value * self.coupon * fraction)
+    }
+
+    /// Returns the value date of a trade in the bond done on the `trade` date, `settlement_lag`
+    /// business days later (e.g. `2` for `T+2`), see [`Business::settlement_from_trade`].
+    ///
+    /// Returns `None` if no such business day exist.
+    #[must_use]
+    pub fn value_date(
+        &self,
+        trade: NaiveDate,
+        settlement_lag: u32,
+        calendar: &dyn Business,
+    ) -> Option<NaiveDate> {
+        calendar.settlement_from_trade(
+            trade,
+            i64::from(settlement_lag),
+            &Tenor::days(0),
+            &BusinessDayConvetion::Following,
+        )
+    }
+
+    /// Calculates the interest accrued to the value date of a trade done on the `trade` date
+    /// with the `settlement_lag` supplied (see [`FixedRateBond::value_date`]), rather than to
+    /// the trade date itself, like [`FixedRateBond::accrued_interest`].
+    ///
+    /// Returns `None` if the value date is outside the bond's life.
+    #[must_use]
+    pub fn accrued_interest_on_trade(
+        &self,
+        trade: NaiveDate,
+        settlement_lag: u32,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        let value_date = self.value_date(trade, settlement_lag, calendar)?;
+        self.accrued_interest(value_date, calendar)
+    }
+
+    /// Calculates the dirty price of the bond settled on the `settlement` (value) date at the
+    /// clean `price`, i.e. the clean price plus the [`accrued_interest`], both in the units of
+    /// the face value.
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life.
+    ///
+    /// [`accrued_interest`]: FixedRateBond::accrued_interest
+    #[must_use]
+    pub fn dirty_price(
+        &self,
+        settlement: NaiveDate,
+        price: Decimal,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        Some(price + self.accrued_interest(settlement, calendar)?)
+    }
+
+    /// Returns the number of days accrued from the start of the current coupon period to the
+    /// `settlement` date and the number of days in the period, both counted with the bond's
+    /// day count convention (e.g. `(42, 180)` for `30/360`), for display.
+    ///
+    /// The days are counted from the start of the period even if the bond trades ex-dividend.
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life.
+    #[must_use]
+    pub fn accrued_days(
+        &self,
+        settlement: NaiveDate,
+        calendar: &dyn Business,
+    ) -> Option<(i64, i64)> {
+        let period = self.period_on(settlement, calendar)?;
+        let days = |start, end| match self.day_count {
+            DayCountConvention::Business252 => {
+                calendar.count_business_days(start, end).map(i64::from)
+            }
+            day_count => day_count.day_count(start, end),
+        };
+
+        Some((
+            days(period.accrual_start(), settlement)?,
+            days(period.accrual_start(), period.accrual_end())?,
+        ))
+    }
+
+    /// Returns the coupon date following the `settlement` date, i.e. the end of the coupon
+    /// period in which the settlement falls.
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life.
+    #[must_use]
+    pub fn next_coupon_date(
+        &self,
+        settlement: NaiveDate,
+        calendar: &dyn Business,
+    ) -> Option<NaiveDate> {
+        Some(self.period_on(settlement, calendar)?.accrual_end())
+    }
+
+    /// Returns the coupon date on or before the `settlement` date, i.e. the start of the coupon
+    /// period in which the settlement falls (the issue date in the first period).
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life.
+    #[must_use]
+    pub fn previous_coupon_date(
+        &self,
+        settlement: NaiveDate,
+        calendar: &dyn Business,
+    ) -> Option<NaiveDate> {
+        Some(self.period_on(settlement, calendar)?.accrual_start())
+    }
+
+    /// Calculates the yield to maturity of the bond bought on the `settlement` date at the clean
+    /// `price`, given in the units of the face value (e.g. `98.5` for a bond with the default
+    /// face value of 100).
+    ///
+    /// The yield is compounded with the coupon frequency of the bond (annually for a bond
+    /// paying a single coupon), and the cashflows are discounted over the number of coupon
+    /// periods to their payment, the first one being the fraction of the current period left
+    /// after the settlement date. The yield is searched by bisection between -50% and 100% to
+    /// within `1e-10`.
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life or no yield within the
+    /// searched range matches the price.
+    #[must_use]
+    pub fn yield_from_price(
+        &self,
+        settlement: NaiveDate,
+        price: Decimal,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        self.yield_to(settlement, price, self.maturity, self.face_value, calendar)
+    }
+
+    /// Calculates the yield to worst of the bond bought on the `settlement` date at the clean
+    /// `price`, i.e. the lowest of the yield to maturity and the yields to each call in the
+    /// [`call_schedule`] after the settlement date, each calculated like in
+    /// [`FixedRateBond::yield_from_price`].
+    ///
+    /// Returns `None` if the settlement date is outside the bond's life or the yield of any of
+    /// the scenarios can not be found.
+    ///
+    /// [`call_schedule`]: FixedRateBond::call_schedule
+    #[must_use]
+    pub fn yield_to_worst(
+        &self,
+        settlement: NaiveDate,
+        price: Decimal,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        let mut worst = self.yield_from_price(settlement, price, calendar)?;
+        for &(call_date, call_price) in &self.call_schedule {
+            if settlement < call_date && call_date < self.maturity {
+                worst =
+                    worst.min(self.yield_to(settlement, price, call_date, call_price, calendar)?);
+            }
+        }
+
+        Some(worst)
+    }
+
+    /// Searches the yield of the bond bought at the clean `price`, assuming it is redeemed at
+    /// the `redemption_price` on the `redemption` date.
+    fn yield_to(
+        &self,
+        settlement: NaiveDate,
+        price: Decimal,
+        redemption: NaiveDate,
+        redemption_price: Decimal,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        let dirty_price = self.dirty_price(settlement, price, calendar)?;
+        let cashflows = self.cashflows_to(settlement, redemption, redemption_price, calendar)?;
+        let frequency = self.frequency.periods_per_year().max(1);
+        let present_value = |rate| -> Option<Decimal> {
+            cashflows
+                .iter()
+                .try_fold(Decimal::ZERO, |sum, &(periods, amount)| {
+                    let years = periods / Decimal::from(frequency);
+                    Some(sum + amount * compound_discount_factor(rate, frequency, years)?)
+                })
+        };
+
+        // The present value decreases with the yield.
+        let (mut low, mut high) = YIELD_BRACKET;
+        if present_value(low)? < dirty_price || present_value(high)? > dirty_price {
+            return None;
+        }
+        while high - low > YIELD_TOLERANCE {
+            let middle = (low + high) / dec!(2);
+            if present_value(middle)? > dirty_price {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        Some((low + high) / dec!(2))
+    }
+
+    /// Returns the cashflows received by the buyer on the `settlement` date until the bond is
+    /// redeemed at the `redemption_price` on the `redemption` date, as pairs of the number of
+    /// coupon periods from the settlement date to the cashflow and its amount.
+    fn cashflows_to(
+        &self,
+        settlement: NaiveDate,
+        redemption: NaiveDate,
+        redemption_price: Decimal,
+        calendar: &dyn Business,
+    ) -> Option<Vec<(Decimal, Decimal)>> {
+        let schedule = self.schedule(calendar)?;
+        let mut periods = schedule
+            .periods()
+            .iter()
+            .filter(|period| settlement < period.accrual_end())
+            .filter(|period| period.accrual_start() < redemption)
+            .peekable();
+
+        let current = **periods.peek()?;
+        let ex_dividend = self.is_ex_dividend(settlement, &current, calendar)?;
+        let mut cashflows = Vec::new();
+        let mut elapsed = Decimal::ZERO;
+        for period in periods {
+            let start = settlement.max(period.accrual_start());
+            let end = redemption.min(period.accrual_end());
+            let length = (period.accrual_end() - period.accrual_start()).num_days();
+            elapsed += Decimal::from((end - start).num_days()) / Decimal::from(length);
+
+            if !(ex_dividend && *period == current) {
+                let fraction = self.fraction(period.accrual_start(), end, period, calendar)?;
+                cashflows.push((elapsed, self.face_value * self.coupon * fraction));
+            }
+            if end == redemption {
+                cashflows.push((elapsed, redemption_price));
+            }
+        }
+
+        Some(cashflows)
+    }
+
+    /// Checks whether the bond trades ex-dividend on the `settlement` date in the coupon
+    /// `period`, i.e. on or after the date `ex_dividend_days` business days before the coupon
+    /// is paid.
+    fn is_ex_dividend(
+        &self,
+        settlement: NaiveDate,
+        period: &CouponPeriod,
+        calendar: &dyn Business,
+    ) -> Option<bool> {
+        if self.ex_dividend_days == 0 {
+            return Some(false);
+        }
+
+        let ex_dividend =
+            calendar.add_business_days(period.payment_date(), -i64::from(self.ex_dividend_days))?;
+        Some(settlement >= ex_dividend)
+    }
+
+    /// Returns the coupon period in which the `settlement` date falls.
+    fn period_on(&self, settlement: NaiveDate, calendar: &dyn Business) -> Option<CouponPeriod> {
+        self.schedule(calendar)?
+            .periods()
+            .iter()
+            .find(|period| {
+                period.accrual_start() <= settlement && settlement < period.accrual_end()
+            })
+            .copied()
+    }
+
+    /// Returns the day count fraction between the dates within the coupon `period`.
+    fn fraction(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        period: &CouponPeriod,
+        calendar: &dyn Business,
+    ) -> Option<Decimal> {
+        match self.day_count {
+            DayCountConvention::ActualActualIcma => act_act_isma(
+                start,
+                end,
+                period.accrual_start(),
+                period.accrual_end(),
+                self.frequency.periods_per_year(),
+            ),
+            DayCountConvention::Business252 => bus_252(start, end, calendar),
+            day_count => day_count.year_fraction(start, end),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::calendar::{target::TargetCalendar, weekend::WeekendCalendar};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A gilt-style 4.25% bond paying semiannually on June 7th and December 7th.
+    fn gilt() -> FixedRateBond {
+        FixedRateBond::new(
+            date(2019, 12, 7),
+            date(2029, 12, 7),
+            dec!(0.0425),
+            Frequency::SemiAnnual,
+            DayCountConvention::ActualActualIcma,
+        )
+        .ex_dividend_days(7)
+    }
+
+    /// The interest on the face value of the gilt over `days` of the 183-day period ending on
+    /// June 7th, 2024.
+    fn interest(days: i64) -> Decimal {
+        dec!(100) * dec!(0.0425) * (Decimal::from(days) / Decimal::from(183 * 2))
+    }
+
+    #[test]
+    fn accrued_interest_before_ex_dividend_date() {
+        // Seven business days before Friday June 7th, 2024 is Wednesday May 29th.
+        assert_eq!(
+            gilt().accrued_interest(date(2024, 5, 28), &WeekendCalendar),
+            Some(interest(173))
+        );
+    }
+
+    #[test]
+    fn accrued_interest_is_negative_ex_dividend() {
+        assert_eq!(
+            gilt().accrued_interest(date(2024, 5, 29), &WeekendCalendar),
+            Some(-interest(9))
+        );
+        assert_eq!(
+            gilt().accrued_interest(date(2024, 6, 6), &WeekendCalendar),
+            Some(-interest(1))
+        );
+    }
+
+    #[test]
+    fn accrued_interest_restarts_on_coupon_date() {
+        assert_eq!(
+            gilt().accrued_interest(date(2024, 6, 7), &WeekendCalendar),
+            Some(Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn accrued_interest_without_ex_dividend_period() {
+        let bond = gilt().ex_dividend_days(0);
+
+        assert_eq!(
+            bond.accrued_interest(date(2024, 5, 29), &WeekendCalendar),
+            Some(interest(174))
+        );
+    }
+
+    #[test]
+    fn coupon_dates_in_first_period() {
+        let bond = gilt();
+        let settlement = date(2020, 3, 1);
+
+        assert_eq!(
+            bond.previous_coupon_date(settlement, &WeekendCalendar),
+            Some(date(2019, 12, 7))
+        );
+        assert_eq!(
+            bond.next_coupon_date(settlement, &WeekendCalendar),
+            Some(date(2020, 6, 7))
+        );
+    }
+
+    #[test]
+    fn coupon_dates_in_last_period() {
+        let bond = gilt();
+        let settlement = date(2029, 10, 1);
+
+        assert_eq!(
+            bond.previous_coupon_date(settlement, &WeekendCalendar),
+            Some(date(2029, 6, 7))
+        );
+        assert_eq!(
+            bond.next_coupon_date(settlement, &WeekendCalendar),
+            Some(date(2029, 12, 7))
+        );
+    }
+
+    #[test]
+    fn coupon_dates_on_coupon_date() {
+        let bond = gilt();
+
+        assert_eq!(
+            bond.previous_coupon_date(date(2024, 6, 7), &WeekendCalendar),
+            Some(date(2024, 6, 7))
+        );
+        assert_eq!(
+            bond.next_coupon_date(date(2024, 6, 7), &WeekendCalendar),
+            Some(date(2024, 12, 7))
+        );
+    }
+
+    #[test]
+    fn coupon_dates_outside_bond_life() {
+        let bond = gilt();
+
+        assert_eq!(
+            bond.previous_coupon_date(date(2019, 12, 6), &WeekendCalendar),
+            None
+        );
+        assert_eq!(
+            bond.next_coupon_date(date(2029, 12, 7), &WeekendCalendar),
+            None
+        );
+    }
+
+    /// A 5% semiannual bond maturing on January 15th, 2034, callable at par two years after
+    /// its issue.
+    fn callable() -> FixedRateBond {
+        FixedRateBond::new(
+            date(2024, 1, 15),
+            date(2034, 1, 15),
+            dec!(0.05),
+            Frequency::SemiAnnual,
+            DayCountConvention::Thirty360,
+        )
+        .call_schedule(vec![(date(2026, 1, 15), dec!(100))])
+    }
+
+    /// The same bond as [`callable`], but maturing on its call date.
+    fn to_call() -> FixedRateBond {
+        FixedRateBond::new(
+            date(2024, 1, 15),
+            date(2026, 1, 15),
+            dec!(0.05),
+            Frequency::SemiAnnual,
+            DayCountConvention::Thirty360,
+        )
+    }
+
+    #[test]
+    fn yield_to_worst_above_par_is_yield_to_call() {
+        let settlement = date(2024, 1, 15);
+        let price = dec!(105);
+        let bond = callable();
+
+        let to_maturity = bond
+            .yield_from_price(settlement, price, &WeekendCalendar)
+            .unwrap();
+        let to_call = to_call()
+            .yield_from_price(settlement, price, &WeekendCalendar)
+            .unwrap();
+        let worst = bond
+            .yield_to_worst(settlement, price, &WeekendCalendar)
+            .unwrap();
+
+        assert!(to_call < to_maturity);
+        assert_eq!(worst, to_call);
+        // About 2.42% to the call, against 4.38% to maturity.
+        assert!((worst - dec!(0.0242)).abs() < dec!(0.0001), "{worst}");
+        assert!(
+            (to_maturity - dec!(0.0438)).abs() < dec!(0.0001),
+            "{to_maturity}"
+        );
+    }
+
+    #[test]
+    fn yield_to_worst_below_par_is_yield_to_maturity() {
+        let settlement = date(2024, 1, 15);
+        let price = dec!(95);
+        let bond = callable();
+
+        assert_eq!(
+            bond.yield_to_worst(settlement, price, &WeekendCalendar),
+            bond.yield_from_price(settlement, price, &WeekendCalendar)
+        );
+        // After the call date, only the maturity is left.
+        assert_eq!(
+            bond.yield_to_worst(date(2026, 6, 1), dec!(105), &WeekendCalendar),
+            bond.yield_from_price(date(2026, 6, 1), dec!(105), &WeekendCalendar)
+        );
+    }
+
+    /// A 5% bond paying semiannually on January 31st and July 31st with a `30/360` day count.
+    fn thirty_360() -> FixedRateBond {
+        FixedRateBond::new(
+            date(2020, 1, 31),
+            date(2030, 1, 31),
+            dec!(0.05),
+            Frequency::SemiAnnual,
+            DayCountConvention::Thirty360,
+        )
+    }
+
+    #[test]
+    fn accrued_days_thirty_360_mid_period() {
+        let bond = thirty_360();
+        // January 31st to March 15th is 30 + 15 days with the 31st treated as the 30th,
+        // although only 44 actual days.
+        assert_eq!(
+            bond.accrued_days(date(2024, 3, 15), &WeekendCalendar),
+            Some((45, 180))
+        );
+        assert_eq!(
+            bond.accrued_days(date(2024, 7, 30), &WeekendCalendar),
+            Some((180, 180))
+        );
+        assert_eq!(
+            bond.accrued_days(date(2024, 1, 31), &WeekendCalendar),
+            Some((0, 180))
+        );
+    }
+
+    #[test]
+    fn accrued_days_actual_and_outside_bond_life() {
+        assert_eq!(
+            gilt().accrued_days(date(2024, 3, 15), &WeekendCalendar),
+            Some((99, 183))
+        );
+        assert_eq!(
+            thirty_360().accrued_days(date(2019, 3, 15), &WeekendCalendar),
+            None
+        );
+    }
+
+    #[test]
+    fn accrued_interest_on_t_plus_2_value_date() {
+        let bond = gilt();
+        // Trade on Tuesday, March 12th, 2024, settled on Thursday, March 14th.
+        let trade = date(2024, 3, 12);
+        let value_date = bond.value_date(trade, 2, &WeekendCalendar);
+        assert_eq!(value_date, Some(date(2024, 3, 14)));
+
+        assert_eq!(
+            bond.accrued_interest(trade, &WeekendCalendar),
+            Some(interest(96))
+        );
+        assert_eq!(
+            bond.accrued_interest_on_trade(trade, 2, &WeekendCalendar),
+            Some(interest(98))
+        );
+        assert_eq!(
+            bond.accrued_interest_on_trade(trade, 0, &WeekendCalendar),
+            bond.accrued_interest(trade, &WeekendCalendar)
+        );
+    }
+
+    #[test]
+    fn accrued_interest_on_trade_across_holidays() {
+        let bond = gilt();
+        // Trade on Thursday, March 28th, 2024: Good Friday and Easter Monday are TARGET
+        // holidays, so the value date is Wednesday, April 3rd, six days later.
+        let trade = date(2024, 3, 28);
+        assert_eq!(
+            bond.value_date(trade, 2, &TargetCalendar),
+            Some(date(2024, 4, 3))
+        );
+        assert_eq!(
+            bond.accrued_interest_on_trade(trade, 2, &TargetCalendar),
+            Some(interest(118))
+        );
+        assert_eq!(
+            bond.accrued_interest_on_trade(trade, 2, &WeekendCalendar),
+            Some(interest(116))
+        );
+    }
+
+    #[test]
+    fn dirty_price_on_value_date() {
+        let bond = gilt();
+        let value_date = bond
+            .value_date(date(2024, 3, 12), 2, &WeekendCalendar)
+            .unwrap();
+        assert_eq!(
+            bond.dirty_price(value_date, dec!(99), &WeekendCalendar),
+            Some(dec!(99) + interest(98))
+        );
+        assert_eq!(
+            bond.dirty_price(date(2030, 1, 1), dec!(99), &WeekendCalendar),
+            None
+        );
+    }
+}
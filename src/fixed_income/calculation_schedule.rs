@@ -1,9 +1,930 @@
-use chrono::NaiveDate;
+//! This module provides types used for the calculation schedule generation.
 
+use std::{
+    error::Error,
+    fmt::{self, Write},
+};
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{
+    calendar::Business, fixed_income::day_count_fraction::DayCountConvention, tenor::Tenor,
+    BusinessDayConvetion,
+};
+
+/// A `Frequency` represents how often the calculation periods of a schedule occur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Frequency {
+    /// One period per year.
+    Annual,
+    /// Two periods per year.
+    SemiAnnual,
+    /// Four periods per year.
+    Quarterly,
+    /// Twelve periods per year.
+    Monthly,
+    /// A single period from the effective to the termination date, e.g. of a zero-coupon bond.
+    Once,
+}
+
+impl Frequency {
+    /// Returns the number of regular periods in a year, which is zero for [`Frequency::Once`].
+    #[must_use]
+    pub fn periods_per_year(&self) -> u32 {
+        match self {
+            Frequency::Annual => 1,
+            Frequency::SemiAnnual => 2,
+            Frequency::Quarterly => 4,
+            Frequency::Monthly => 12,
+            Frequency::Once => 0,
+        }
+    }
+
+    /// Returns the length of a regular period, or `None` for [`Frequency::Once`], whose single
+    /// period has no fixed length.
+    #[must_use]
+    pub fn tenor(&self) -> Option<Tenor> {
+        match self {
+            Frequency::Annual => Some(Tenor::Years(1)),
+            Frequency::SemiAnnual => Some(Tenor::Months(6)),
+            Frequency::Quarterly => Some(Tenor::Months(3)),
+            Frequency::Monthly => Some(Tenor::Months(1)),
+            Frequency::Once => None,
+        }
+    }
+}
+
+/// A `Generation` represents the direction in which the regular periods of a schedule
+/// are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Generation {
+    /// The periods are rolled forward from the effective date, leaving a stub period
+    /// at the end of the schedule.
+    Forward,
+    /// The periods are rolled backward from the termination date, leaving a stub period
+    /// at the start of the schedule.
+    Backward,
+}
+
+/// A `RollConvention` represents the day of month on which the unadjusted regular period
+/// dates of a schedule fall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RollConvention {
+    /// The given day of month, clamped to the length of the month (see [`apply_roll_day`]).
+    Day(u32),
+    /// The third Wednesday of the month, i.e. the `IMM` date in the `IMM` months.
+    Imm,
+}
+
+impl RollConvention {
+    /// Moves the date within its month to the roll day.
+    ///
+    /// Returns `None` if the date is out of range.
+    #[must_use]
+    pub fn apply(&self, date: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            RollConvention::Day(roll_day) => Some(apply_roll_day(date, *roll_day)),
+            RollConvention::Imm => {
+                NaiveDate::from_weekday_of_month_opt(date.year(), date.month(), Weekday::Wed, 3)
+            }
+        }
+    }
+}
+
+/// Sets the day of month of the date to the `roll_day`, clamped to the length of the month,
+/// e.g. roll day 31 in February is the 28th or 29th.
+#[must_use]
+pub fn apply_roll_day(date: NaiveDate, roll_day: u32) -> NaiveDate {
+    let last = crate::tenor::last_day_of_month(date.year(), date.month()).unwrap_or(date);
+
+    last.with_day(roll_day.clamp(1, last.day())).unwrap_or(last)
+}
+
+/// A `CouponPeriod` represents a single calculation period of a [`Schedule`],
+/// with its adjusted accrual and payment dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CouponPeriod {
+    accrual_start: NaiveDate,
+    accrual_end: NaiveDate,
+    payment_date: NaiveDate,
+}
+
+impl CouponPeriod {
+    /// Returns the first day of the accrual period.
+    #[must_use]
+    pub fn accrual_start(&self) -> NaiveDate {
+        self.accrual_start
+    }
+
+    /// Returns the end of the accrual period (not accrued itself).
+    #[must_use]
+    pub fn accrual_end(&self) -> NaiveDate {
+        self.accrual_end
+    }
+
+    /// Returns the date on which the period's cashflow is paid.
+    #[must_use]
+    pub fn payment_date(&self) -> NaiveDate {
+        self.payment_date
+    }
+}
+
+/// A `Schedule` represents the calculation periods of a fixed income instrument,
+/// with optional initial and final stub periods.
+///
+/// The regular periods run from `first_regular` to `last_regular`. If present, `first` is the
+/// start of the initial stub period and `last` is the end of the final stub period.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Schedule {
     first: Option<NaiveDate>,
     first_regular: NaiveDate,
     last: Option<NaiveDate>,
-    last_regular: NaiveDate
-    
-}
\ No newline at end of file
+    last_regular: NaiveDate,
+    frequency: Frequency,
+    periods: Vec<CouponPeriod>,
+}
+
+impl Schedule {
+    /// Returns the unadjusted start of the initial stub period, if there is one.
+    #[must_use]
+    pub fn first(&self) -> Option<NaiveDate> {
+        self.first
+    }
+
+    /// Returns the unadjusted start of the first regular period.
+    #[must_use]
+    pub fn first_regular(&self) -> NaiveDate {
+        self.first_regular
+    }
+
+    /// Returns the unadjusted end of the final stub period, if there is one.
+    #[must_use]
+    pub fn last(&self) -> Option<NaiveDate> {
+        self.last
+    }
+
+    /// Returns the unadjusted end of the last regular period.
+    #[must_use]
+    pub fn last_regular(&self) -> NaiveDate {
+        self.last_regular
+    }
+
+    /// Returns the frequency of the regular periods.
+    #[must_use]
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// Returns the calculation periods of the schedule.
+    #[must_use]
+    pub fn periods(&self) -> &[CouponPeriod] {
+        &self.periods
+    }
+
+    /// Checks whether the schedule starts with a stub period, i.e. whether the effective date
+    /// is not a whole number of regular periods before the first regular period.
+    #[must_use]
+    pub fn has_front_stub(&self) -> bool {
+        self.first.is_some_and(|first| first != self.first_regular)
+    }
+
+    /// Checks whether the schedule ends with a stub period, i.e. whether the termination date
+    /// is not a whole number of regular periods after the last regular period.
+    #[must_use]
+    pub fn has_back_stub(&self) -> bool {
+        self.last.is_some_and(|last| last != self.last_regular)
+    }
+
+    /// Returns the length of each calculation period in days, from the adjusted accrual start
+    /// to the adjusted accrual end.
+    #[must_use]
+    pub fn period_lengths(&self) -> Vec<i64> {
+        self.periods
+            .iter()
+            .map(|period| (period.accrual_end - period.accrual_start).num_days())
+            .collect()
+    }
+
+    /// Returns the schedule as a CSV table with a header row and one row per period, in the
+    /// form of `accrual_start,accrual_end,payment_date,day_count_fraction`.
+    ///
+    /// Dates are formatted using `ISO 8601`. The day count fraction is calculated using the
+    /// `day_count` convention supplied and left empty if it cannot be calculated.
+    #[must_use]
+    pub fn to_csv(&self, day_count: &DayCountConvention) -> String {
+        let mut csv = String::from("accrual_start,accrual_end,payment_date,day_count_fraction\n");
+
+        for period in &self.periods {
+            let fraction = day_count
+                .year_fraction(period.accrual_start, period.accrual_end)
+                .map(|fraction| fraction.to_string())
+                .unwrap_or_default();
+            // Writing to a `String` cannot fail.
+            let _ = writeln!(
+                csv,
+                "{},{},{},{}",
+                period.accrual_start, period.accrual_end, period.payment_date, fraction
+            );
+        }
+
+        csv
+    }
+
+    /// Checks that the periods are contiguous and their dates strictly increasing, i.e. that
+    /// each period ends after it starts and the next period starts where it ends.
+    ///
+    /// A schedule built by [`ScheduleBuilder`] pairs consecutive adjusted dates into periods, so
+    /// it is always contiguous and can only fail with [`ScheduleError::NotIncreasing`], e.g. when
+    /// an explicit first coupon date is adjusted onto the effective date.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`ScheduleError`] of the first period which is empty, overlaps the previous
+    /// period or leaves a gap after it.
+    pub fn validate(&self) -> Result<(), ScheduleError> {
+        for (index, period) in self.periods.iter().enumerate() {
+            if period.accrual_start >= period.accrual_end {
+                return Err(ScheduleError::NotIncreasing { index });
+            }
+
+            let Some(previous) = index.checked_sub(1) else {
+                continue;
+            };
+            let previous_end = self.periods[previous].accrual_end;
+            if period.accrual_start < previous_end {
+                return Err(ScheduleError::Overlap { index });
+            }
+            if period.accrual_start > previous_end {
+                return Err(ScheduleError::Gap { index });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A `ScheduleError` represents an invalid period of a [`Schedule`], see
+/// [`Schedule::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScheduleError {
+    /// The period does not end after it starts.
+    NotIncreasing {
+        /// The index of the period.
+        index: usize,
+    },
+    /// The period starts before the end of the previous period.
+    Overlap {
+        /// The index of the period.
+        index: usize,
+    },
+    /// The period starts after the end of the previous period.
+    Gap {
+        /// The index of the period.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScheduleError::NotIncreasing { index } => {
+                write!(f, "period {index} does not end after it starts")
+            }
+            ScheduleError::Overlap { index } => {
+                write!(f, "period {index} overlaps the previous period")
+            }
+            ScheduleError::Gap { index } => {
+                write!(f, "period {index} leaves a gap after the previous period")
+            }
+        }
+    }
+}
+
+impl Error for ScheduleError {}
+
+/// A `ScheduleBuilder` generates a [`Schedule`] between the effective and termination dates.
+///
+/// Regular periods are rolled from the effective or the termination date, depending on the
+/// [`Generation`], leaving a stub period at the other end of the schedule if the dates are
+/// not an exact number of periods apart.
+#[derive(Debug, Clone)]
+pub struct ScheduleBuilder {
+    effective: NaiveDate,
+    termination: NaiveDate,
+    frequency: Frequency,
+    convention: BusinessDayConvetion,
+    payment_convention: Option<BusinessDayConvetion>,
+    end_of_month: bool,
+    generation: Generation,
+    roll: Option<RollConvention>,
+    first_coupon_date: Option<NaiveDate>,
+    penultimate_coupon_date: Option<NaiveDate>,
+}
+
+impl ScheduleBuilder {
+    /// Creates a builder for a schedule between the `effective` and `termination` dates,
+    /// generated forward, with no date adjustment and no end-of-month rule.
+    #[must_use]
+    pub fn new(effective: NaiveDate, termination: NaiveDate, frequency: Frequency) -> Self {
+        ScheduleBuilder {
+            effective,
+            termination,
+            frequency,
+            convention: BusinessDayConvetion::NoAdjustment,
+            payment_convention: None,
+            end_of_month: false,
+            generation: Generation::Forward,
+            roll: None,
+            first_coupon_date: None,
+            penultimate_coupon_date: None,
+        }
+    }
+
+    /// Sets the business day convention used to adjust the schedule dates, i.e. the accrual
+    /// dates of the periods. With [`BusinessDayConvetion::NoAdjustment`], the unadjusted dates
+    /// are kept even if they fall on a weekend or a holiday.
+    ///
+    /// The payment dates are the adjusted accrual end dates, unless a separate
+    /// [`payment_convention`] is set.
+    ///
+    /// [`payment_convention`]: ScheduleBuilder::payment_convention
+    #[must_use]
+    pub fn convention(mut self, convention: BusinessDayConvetion) -> Self {
+        self.convention = convention;
+        self
+    }
+
+    /// Sets the business day convention used to adjust the payment dates, separately from the
+    /// accrual dates, e.g. unadjusted accrual dates paid on the modified following business
+    /// day. The payment date of a period is its unadjusted end date adjusted with this
+    /// convention.
+    #[must_use]
+    pub fn payment_convention(mut self, payment_convention: BusinessDayConvetion) -> Self {
+        self.payment_convention = Some(payment_convention);
+        self
+    }
+
+    /// Sets whether the end-of-month rule applies, i.e. whether a schedule rolled from
+    /// the last day of a month keeps rolling on the last day of a month.
+    #[must_use]
+    pub fn end_of_month(mut self, end_of_month: bool) -> Self {
+        self.end_of_month = end_of_month;
+        self
+    }
+
+    /// Sets the direction in which the regular periods are generated.
+    #[must_use]
+    pub fn generation(mut self, generation: Generation) -> Self {
+        self.generation = generation;
+        self
+    }
+
+    /// Sets the roll convention applied to the unadjusted regular period dates, other than the
+    /// effective and termination dates. By default, the day of month of the anchor date is kept.
+    #[must_use]
+    pub fn roll(mut self, roll: RollConvention) -> Self {
+        self.roll = Some(roll);
+        self
+    }
+
+    /// Sets the end of the first period, forcing a front stub period from the effective date
+    /// to it. The regular periods are generated from this date.
+    #[must_use]
+    pub fn first_coupon_date(mut self, first_coupon_date: NaiveDate) -> Self {
+        self.first_coupon_date = Some(first_coupon_date);
+        self
+    }
+
+    /// Sets the start of the last period, forcing a back stub period from it to the termination
+    /// date. The regular periods are generated up to this date.
+    #[must_use]
+    pub fn penultimate_coupon_date(mut self, penultimate_coupon_date: NaiveDate) -> Self {
+        self.penultimate_coupon_date = Some(penultimate_coupon_date);
+        self
+    }
+
+    /// Generates the schedule, adjusting its dates using the `calendar` supplied.
+    ///
+    /// The regular periods run from the first coupon date (or the effective date) to the
+    /// penultimate coupon date (or the termination date), in the direction of the
+    /// [`Generation`]. With [`Frequency::Once`], there is a single period between them.
+    ///
+    /// Returns `None` if the effective date is not before the termination date, the first
+    /// coupon date is not between the effective date and the penultimate coupon date, the
+    /// penultimate coupon date is not between the first coupon date and the termination date,
+    /// or any date cannot be adjusted.
+    #[must_use]
+    pub fn build(&self, calendar: &dyn Business) -> Option<Schedule> {
+        if self.effective >= self.termination {
+            return None;
+        }
+
+        let start = self.first_coupon_date.unwrap_or(self.effective);
+        let end = self.penultimate_coupon_date.unwrap_or(self.termination);
+        if start < self.effective || end > self.termination || start > end {
+            return None;
+        }
+
+        let (mut dates, first_regular, last_regular) = if start == end {
+            (vec![start], start, end)
+        } else if self.frequency == Frequency::Once {
+            (vec![start, end], start, end)
+        } else {
+            match self.generation {
+                Generation::Forward => self.forward(start, end)?,
+                Generation::Backward => self.backward(start, end)?,
+            }
+        };
+        if start != self.effective {
+            dates.insert(0, self.effective);
+        }
+        if end != self.termination {
+            dates.push(self.termination);
+        }
+
+        Some(Schedule {
+            first: (first_regular != self.effective).then_some(self.effective),
+            first_regular,
+            last: (last_regular != self.termination).then_some(self.termination),
+            last_regular,
+            frequency: self.frequency,
+            periods: self.periods(&dates, calendar)?,
+        })
+    }
+
+    /// Generates the unadjusted dates from `start` to `end`, rolling forward from `start`, and
+    /// returns them together with the start and the end of the regular periods.
+    fn forward(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Option<(Vec<NaiveDate>, NaiveDate, NaiveDate)> {
+        let mut dates = vec![start];
+        let mut step = 1;
+        let mut date = self.roll_by(start, step)?;
+        while date < end {
+            dates.push(date);
+            step += 1;
+            date = self.roll_by(start, step)?;
+        }
+
+        let last_regular = if date == end { end } else { *dates.last()? };
+        dates.push(end);
+
+        Some((dates, start, last_regular))
+    }
+
+    /// Generates the unadjusted dates from `start` to `end`, rolling backward from `end`, and
+    /// returns them together with the start and the end of the regular periods.
+    fn backward(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Option<(Vec<NaiveDate>, NaiveDate, NaiveDate)> {
+        let mut dates = vec![end];
+        let mut step = 1;
+        let mut date = self.roll_by(end, -step)?;
+        while date > start {
+            dates.push(date);
+            step += 1;
+            date = self.roll_by(end, -step)?;
+        }
+
+        let first_regular = if date == start { start } else { *dates.last()? };
+        dates.push(start);
+        dates.reverse();
+
+        Some((dates, first_regular, end))
+    }
+
+    /// Returns the unadjusted date `steps` regular periods away from `anchor`.
+    fn roll_by(&self, anchor: NaiveDate, steps: i32) -> Option<NaiveDate> {
+        let tenor = match self.frequency.tenor()? {
+            Tenor::Days(days) => Tenor::Days(days.checked_mul(steps)?),
+            Tenor::Weeks(weeks) => Tenor::Weeks(weeks.checked_mul(steps)?),
+            Tenor::Months(months) => Tenor::Months(months.checked_mul(steps)?),
+            Tenor::Years(years) => Tenor::Years(years.checked_mul(steps)?),
+        };
+
+        let date = tenor.advance(anchor, self.end_of_month)?;
+        match self.roll {
+            Some(roll) => roll.apply(date),
+            None => Some(date),
+        }
+    }
+
+    /// Adjusts the unadjusted period boundaries and pairs them into calculation periods, paying
+    /// each on its adjusted end date or with the payment convention.
+    fn periods(&self, dates: &[NaiveDate], calendar: &dyn Business) -> Option<Vec<CouponPeriod>> {
+        let adjusted = dates
+            .iter()
+            .map(|date| calendar.adjust(*date, &self.convention))
+            .collect::<Option<Vec<NaiveDate>>>()?;
+
+        (1..dates.len())
+            .map(|end| {
+                let payment_date = match self.payment_convention {
+                    Some(convention) => calendar.adjust(dates[end], &convention)?,
+                    None => adjusted[end],
+                };
+                Some(CouponPeriod {
+                    accrual_start: adjusted[end - 1],
+                    accrual_end: adjusted[end],
+                    payment_date,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{hash_map::DefaultHasher, HashMap},
+        hash::{Hash, Hasher},
+    };
+
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::calendar::{target::TargetCalendar, weekend::AlwaysBusinessCalendar};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn to_csv_header_and_first_row() {
+        // July 16th, 2023 is a Sunday, so the first period ends on Monday July 17th.
+        let schedule =
+            ScheduleBuilder::new(date(2023, 1, 16), date(2024, 1, 16), Frequency::SemiAnnual)
+                .convention(BusinessDayConvetion::ModifiedFollowiing)
+                .build(&TargetCalendar)
+                .unwrap();
+
+        let csv = schedule.to_csv(&DayCountConvention::Thirty360);
+        let mut rows = csv.lines();
+
+        assert_eq!(
+            rows.next(),
+            Some("accrual_start,accrual_end,payment_date,day_count_fraction")
+        );
+        assert_eq!(
+            rows.next(),
+            Some("2023-01-16,2023-07-17,2023-07-17,0.5027777777777777777777777778")
+        );
+        assert_eq!(
+            rows.next(),
+            Some("2023-07-17,2024-01-16,2024-01-16,0.4972222222222222222222222222")
+        );
+        assert_eq!(rows.next(), None);
+    }
+
+    fn accrual_dates(schedule: &Schedule) -> Vec<(NaiveDate, NaiveDate)> {
+        schedule
+            .periods()
+            .iter()
+            .map(|period| (period.accrual_start(), period.accrual_end()))
+            .collect()
+    }
+
+    #[test]
+    fn backward_generation_puts_stub_at_front() {
+        // Five and a half years and two weeks, so one of the periods is a two-week stub.
+        let builder =
+            ScheduleBuilder::new(date(2020, 3, 1), date(2025, 9, 15), Frequency::SemiAnnual);
+        let forward = builder.clone().build(&AlwaysBusinessCalendar).unwrap();
+        let backward = builder
+            .generation(Generation::Backward)
+            .build(&AlwaysBusinessCalendar)
+            .unwrap();
+
+        assert_eq!(forward.periods().len(), 12);
+        assert!(!forward.has_front_stub());
+        assert!(forward.has_back_stub());
+        assert_eq!(forward.last_regular(), date(2025, 9, 1));
+        assert_eq!(
+            accrual_dates(&forward).last(),
+            Some(&(date(2025, 9, 1), date(2025, 9, 15)))
+        );
+
+        assert_eq!(backward.periods().len(), 12);
+        assert!(backward.has_front_stub());
+        assert!(!backward.has_back_stub());
+        assert_eq!(backward.first_regular(), date(2020, 3, 15));
+        assert_eq!(
+            accrual_dates(&backward).first(),
+            Some(&(date(2020, 3, 1), date(2020, 3, 15)))
+        );
+        assert_eq!(
+            accrual_dates(&backward)[1..3],
+            [
+                (date(2020, 3, 15), date(2020, 9, 15)),
+                (date(2020, 9, 15), date(2021, 3, 15)),
+            ]
+        );
+    }
+
+    #[test]
+    fn forward_and_backward_agree_without_stub() {
+        let builder =
+            ScheduleBuilder::new(date(2020, 3, 15), date(2025, 9, 15), Frequency::SemiAnnual);
+        let forward = builder.clone().build(&AlwaysBusinessCalendar).unwrap();
+        let backward = builder
+            .generation(Generation::Backward)
+            .build(&AlwaysBusinessCalendar)
+            .unwrap();
+
+        assert_eq!(forward.periods().len(), 11);
+        assert_eq!(accrual_dates(&forward), accrual_dates(&backward));
+        assert!(!backward.has_front_stub() && !backward.has_back_stub());
+    }
+
+    #[test]
+    fn roll_day_31_clamps_in_february() {
+        assert_eq!(apply_roll_day(date(2023, 2, 10), 31), date(2023, 2, 28));
+        assert_eq!(apply_roll_day(date(2024, 2, 10), 31), date(2024, 2, 29));
+        assert_eq!(apply_roll_day(date(2024, 4, 10), 31), date(2024, 4, 30));
+        assert_eq!(apply_roll_day(date(2024, 3, 10), 31), date(2024, 3, 31));
+    }
+
+    #[test]
+    fn roll_day_20() {
+        assert_eq!(apply_roll_day(date(2024, 2, 10), 20), date(2024, 2, 20));
+        assert_eq!(apply_roll_day(date(2024, 6, 30), 20), date(2024, 6, 20));
+        assert_eq!(
+            RollConvention::Day(20).apply(date(2024, 6, 1)),
+            Some(date(2024, 6, 20))
+        );
+
+        let schedule =
+            ScheduleBuilder::new(date(2024, 1, 20), date(2025, 1, 20), Frequency::Quarterly)
+                .roll(RollConvention::Day(20))
+                .build(&AlwaysBusinessCalendar)
+                .unwrap();
+
+        assert_eq!(
+            accrual_dates(&schedule),
+            [
+                (date(2024, 1, 20), date(2024, 4, 20)),
+                (date(2024, 4, 20), date(2024, 7, 20)),
+                (date(2024, 7, 20), date(2024, 10, 20)),
+                (date(2024, 10, 20), date(2025, 1, 20)),
+            ]
+        );
+    }
+
+    #[test]
+    fn imm_roll_is_third_wednesday() {
+        assert_eq!(
+            RollConvention::Imm.apply(date(2024, 3, 1)),
+            Some(date(2024, 3, 20))
+        );
+        assert_eq!(
+            RollConvention::Imm.apply(date(2024, 6, 30)),
+            Some(date(2024, 6, 19))
+        );
+    }
+
+    #[test]
+    fn clean_schedule_has_no_stubs() {
+        let schedule =
+            ScheduleBuilder::new(date(2024, 1, 15), date(2025, 1, 15), Frequency::Quarterly)
+                .build(&AlwaysBusinessCalendar)
+                .unwrap();
+
+        assert!(!schedule.has_front_stub());
+        assert!(!schedule.has_back_stub());
+        assert_eq!(schedule.period_lengths(), [91, 91, 92, 92]);
+    }
+
+    #[test]
+    fn short_front_stub() {
+        let schedule =
+            ScheduleBuilder::new(date(2024, 2, 15), date(2025, 1, 15), Frequency::Quarterly)
+                .generation(Generation::Backward)
+                .build(&AlwaysBusinessCalendar)
+                .unwrap();
+
+        assert!(schedule.has_front_stub());
+        assert!(!schedule.has_back_stub());
+        assert_eq!(schedule.first_regular(), date(2024, 4, 15));
+        assert_eq!(schedule.period_lengths(), [60, 91, 92, 92]);
+    }
+
+    #[test]
+    fn zero_coupon_schedule_has_one_period() {
+        // A three-year zero-coupon bond; January 15th, 2028 is a Saturday.
+        let schedule = ScheduleBuilder::new(date(2025, 1, 15), date(2028, 1, 15), Frequency::Once)
+            .convention(BusinessDayConvetion::Following)
+            .build(&TargetCalendar)
+            .unwrap();
+
+        assert_eq!(
+            accrual_dates(&schedule),
+            [(date(2025, 1, 15), date(2028, 1, 17))]
+        );
+        assert_eq!(schedule.periods()[0].payment_date(), date(2028, 1, 17));
+        assert!(!schedule.has_front_stub());
+        assert!(!schedule.has_back_stub());
+        assert_eq!(Frequency::Once.tenor(), None);
+    }
+
+    #[test]
+    fn explicit_first_coupon_date_then_quarterly() {
+        // Issued on January 15th, 2024 with the first coupon two months later.
+        let schedule =
+            ScheduleBuilder::new(date(2024, 1, 15), date(2025, 3, 15), Frequency::Quarterly)
+                .first_coupon_date(date(2024, 3, 15))
+                .build(&AlwaysBusinessCalendar)
+                .unwrap();
+
+        assert_eq!(
+            accrual_dates(&schedule),
+            [
+                (date(2024, 1, 15), date(2024, 3, 15)),
+                (date(2024, 3, 15), date(2024, 6, 15)),
+                (date(2024, 6, 15), date(2024, 9, 15)),
+                (date(2024, 9, 15), date(2024, 12, 15)),
+                (date(2024, 12, 15), date(2025, 3, 15)),
+            ]
+        );
+        assert!(schedule.has_front_stub());
+        assert!(!schedule.has_back_stub());
+        assert_eq!(schedule.first_regular(), date(2024, 3, 15));
+    }
+
+    #[test]
+    fn explicit_penultimate_coupon_date() {
+        let schedule =
+            ScheduleBuilder::new(date(2024, 1, 15), date(2025, 1, 31), Frequency::Quarterly)
+                .first_coupon_date(date(2024, 3, 15))
+                .penultimate_coupon_date(date(2024, 12, 15))
+                .build(&AlwaysBusinessCalendar)
+                .unwrap();
+
+        assert_eq!(
+            accrual_dates(&schedule),
+            [
+                (date(2024, 1, 15), date(2024, 3, 15)),
+                (date(2024, 3, 15), date(2024, 6, 15)),
+                (date(2024, 6, 15), date(2024, 9, 15)),
+                (date(2024, 9, 15), date(2024, 12, 15)),
+                (date(2024, 12, 15), date(2025, 1, 31)),
+            ]
+        );
+        assert!(schedule.has_front_stub());
+        assert!(schedule.has_back_stub());
+        assert_eq!(schedule.last_regular(), date(2024, 12, 15));
+    }
+
+    #[test]
+    fn first_coupon_date_outside_schedule() {
+        let builder =
+            ScheduleBuilder::new(date(2024, 1, 15), date(2025, 3, 15), Frequency::Quarterly);
+
+        assert!(builder
+            .clone()
+            .first_coupon_date(date(2024, 1, 10))
+            .build(&AlwaysBusinessCalendar)
+            .is_none());
+        assert!(builder
+            .first_coupon_date(date(2024, 6, 15))
+            .penultimate_coupon_date(date(2024, 3, 15))
+            .build(&AlwaysBusinessCalendar)
+            .is_none());
+    }
+
+    fn hash_of(schedule: &Schedule) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        schedule.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn identical_schedules_are_equal_cache_keys() {
+        let build = || {
+            ScheduleBuilder::new(date(2024, 1, 15), date(2026, 1, 15), Frequency::Quarterly)
+                .convention(BusinessDayConvetion::ModifiedFollowiing)
+                .build(&TargetCalendar)
+                .unwrap()
+        };
+        let (first, second) = (build(), build());
+
+        assert_eq!(first, second);
+        assert_eq!(hash_of(&first), hash_of(&second));
+        assert_eq!(first.periods()[0], second.periods()[0]);
+
+        let mut cache = HashMap::new();
+        cache.insert(first, 1);
+        assert_eq!(cache.get(&second), Some(&1));
+
+        let other =
+            ScheduleBuilder::new(date(2024, 1, 15), date(2026, 1, 15), Frequency::SemiAnnual)
+                .build(&TargetCalendar)
+                .unwrap();
+        assert_ne!(other, second);
+        assert_eq!(cache.get(&other), None);
+    }
+
+    /// A semiannual schedule from Saturday, March 16th, 2024 to September 16th, 2026, with
+    /// unadjusted accrual dates.
+    fn raw_accrual_schedule() -> ScheduleBuilder {
+        ScheduleBuilder::new(date(2024, 3, 16), date(2026, 9, 16), Frequency::SemiAnnual)
+            .convention(BusinessDayConvetion::NoAdjustment)
+    }
+
+    #[test]
+    fn raw_accrual_dates_with_rolled_payment_dates() {
+        let schedule = raw_accrual_schedule()
+            .payment_convention(BusinessDayConvetion::ModifiedFollowiing)
+            .build(&TargetCalendar)
+            .unwrap();
+        let periods = schedule.periods();
+
+        assert_eq!(periods.len(), 5);
+        // March 16th, 2025 is a Sunday: the periods accrue to and from it, but the coupon is
+        // paid on Monday, March 17th.
+        assert_eq!(periods[1].accrual_end(), date(2025, 3, 16));
+        assert_eq!(periods[2].accrual_start(), date(2025, 3, 16));
+        assert_eq!(periods[1].payment_date(), date(2025, 3, 17));
+        assert_eq!(periods[0].accrual_start(), date(2024, 3, 16));
+        for period in periods {
+            assert!(
+                TargetCalendar.is_business(period.payment_date()),
+                "{period:?}"
+            );
+        }
+        assert_eq!(periods[4].payment_date(), date(2026, 9, 16));
+    }
+
+    #[test]
+    fn payment_dates_follow_accrual_convention_by_default() {
+        let raw = raw_accrual_schedule().build(&TargetCalendar).unwrap();
+        assert_eq!(raw.periods()[1].payment_date(), date(2025, 3, 16));
+
+        let adjusted = raw_accrual_schedule()
+            .convention(BusinessDayConvetion::ModifiedFollowiing)
+            .build(&TargetCalendar)
+            .unwrap();
+        for period in adjusted.periods() {
+            assert_eq!(period.payment_date(), period.accrual_end(), "{period:?}");
+        }
+        assert_eq!(adjusted.periods()[1].accrual_end(), date(2025, 3, 17));
+    }
+
+    /// A quarterly schedule from March 15th, 2024 to January 15th, 2025 with a front stub to
+    /// the explicit first coupon date of April 15th, 2024.
+    fn explicit_front_stub() -> Schedule {
+        ScheduleBuilder::new(date(2024, 3, 15), date(2025, 1, 15), Frequency::Quarterly)
+            .first_coupon_date(date(2024, 4, 15))
+            .build(&AlwaysBusinessCalendar)
+            .unwrap()
+    }
+
+    #[test]
+    fn validate_contiguous_schedule() {
+        let schedule = explicit_front_stub();
+        assert!(schedule.has_front_stub());
+        assert_eq!(schedule.validate(), Ok(()));
+    }
+
+    // The builder cannot produce overlapping or disjoint periods, so the stub period is
+    // changed directly.
+    #[test]
+    fn validate_reports_overlap() {
+        // The stub ends on April 20th, after the first regular period starts on April 15th.
+        let mut schedule = explicit_front_stub();
+        schedule.periods[0].accrual_end = date(2024, 4, 20);
+
+        let error = schedule.validate().unwrap_err();
+        assert_eq!(error, ScheduleError::Overlap { index: 1 });
+        assert_eq!(error.to_string(), "period 1 overlaps the previous period");
+    }
+
+    #[test]
+    fn validate_reports_gap() {
+        let mut schedule = explicit_front_stub();
+        schedule.periods[0].accrual_end = date(2024, 4, 10);
+
+        assert_eq!(schedule.validate(), Err(ScheduleError::Gap { index: 1 }));
+    }
+
+    #[test]
+    fn validate_stub_adjusted_onto_effective_date() {
+        // The first coupon date, Saturday, March 16th, 2024, is adjusted back onto the
+        // effective date, Friday, March 15th, leaving an empty stub.
+        let schedule =
+            ScheduleBuilder::new(date(2024, 3, 15), date(2025, 3, 16), Frequency::Quarterly)
+                .first_coupon_date(date(2024, 3, 16))
+                .convention(BusinessDayConvetion::Preceding)
+                .build(&TargetCalendar)
+                .unwrap();
+
+        assert_eq!(
+            schedule.validate(),
+            Err(ScheduleError::NotIncreasing { index: 0 })
+        );
+    }
+}
@@ -0,0 +1,420 @@
+//! This module provides functions operating on dated cashflows.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rust_decimal::{prelude::*, Decimal};
+
+use crate::fixed_income::{
+    amortization::AmortizationSchedule,
+    calculation_schedule::{CouponPeriod, Schedule},
+    coupon_rate::CouponRateSchedule,
+    day_count_fraction::DayCountConvention,
+};
+
+const XIRR_MAX_ITERATIONS: u32 = 100;
+const XIRR_TOLERANCE: f64 = 1e-10;
+
+/// Calculates the internal rate of return of irregularly spaced cashflows, equivalent to the
+/// Excel `XIRR` function.
+///
+/// The rate is solved with the Newton-Raphson method, starting from `guess`. Each cashflow
+/// is discounted by `(1 + rate)^t`, where `t` is the year fraction between the date of the
+/// first cashflow and the cashflow date, calculated using the `convention` supplied
+/// (Excel uses [`DayCountConvention::Actual365Fixed`]).
+///
+/// Returns `None` if there are no positive and negative cashflows, any cashflow precedes the
+/// first one, or the method does not converge.
+#[must_use]
+pub fn xirr(
+    cashflows: &[(NaiveDate, Decimal)],
+    convention: &DayCountConvention,
+    guess: Decimal,
+) -> Option<Decimal> {
+    let (first, _) = cashflows.first()?;
+
+    let has_positive = cashflows
+        .iter()
+        .any(|(_, amount)| amount.is_sign_positive() && !amount.is_zero());
+    let has_negative = cashflows
+        .iter()
+        .any(|(_, amount)| amount.is_sign_negative() && !amount.is_zero());
+    if !has_positive || !has_negative {
+        return None;
+    }
+
+    let flows = cashflows
+        .iter()
+        .map(|(date, amount)| {
+            let time = convention.year_fraction(*first, *date)?.to_f64()?;
+            Some((time, amount.to_f64()?))
+        })
+        .collect::<Option<Vec<(f64, f64)>>>()?;
+
+    let mut rate = guess.to_f64()?;
+    for _ in 0..XIRR_MAX_ITERATIONS {
+        let base = 1.0 + rate;
+        if base <= 0.0 {
+            return None;
+        }
+
+        let (npv, derivative) =
+            flows
+                .iter()
+                .fold((0.0, 0.0), |(npv, derivative), (time, amount)| {
+                    let discounted = amount / base.powf(*time);
+                    (npv + discounted, derivative - time * discounted / base)
+                });
+
+        if derivative == 0.0 || !derivative.is_finite() {
+            return None;
+        }
+
+        let next = rate - npv / derivative;
+        if !next.is_finite() {
+            return None;
+        }
+        if (next - rate).abs() < XIRR_TOLERANCE {
+            return Decimal::from_f64(next);
+        }
+        rate = next;
+    }
+
+    None
+}
+
+/// Returns the cashflows of a fixed rate instrument paying `rate` on the notional outstanding
+/// in each period of the `schedule`, as pairs of payment date and amount.
+///
+/// Each cashflow is the coupon, calculated as the outstanding notional multiplied by the rate
+/// and the period's day count fraction, plus the principal repaid at the end of the period
+/// according to the `amortization` schedule.
+///
+/// Returns `None` if a day count fraction cannot be calculated or the amortization schedule
+/// does not match the schedule's periods.
+#[must_use]
+pub fn fixed_rate_cashflows(
+    schedule: &Schedule,
+    rate: Decimal,
+    notional: Decimal,
+    day_count: &DayCountConvention,
+    amortization: &AmortizationSchedule,
+) -> Option<Vec<(NaiveDate, Decimal)>> {
+    cashflows(schedule, |_| Some(rate), notional, *day_count, amortization)
+}
+
+/// Returns the cashflows of an instrument paying the coupon rates of the `rates` schedule,
+/// as pairs of payment date and amount.
+///
+/// The rate of each period is the one applicable on its accrual start date. Otherwise, the
+/// cashflows are calculated as in [`fixed_rate_cashflows`].
+///
+/// Returns `None` if the rate schedule does not cover an accrual start date, or under the
+/// same conditions as [`fixed_rate_cashflows`].
+#[must_use]
+pub fn stepped_rate_cashflows(
+    schedule: &Schedule,
+    rates: &CouponRateSchedule,
+    notional: Decimal,
+    day_count: &DayCountConvention,
+    amortization: &AmortizationSchedule,
+) -> Option<Vec<(NaiveDate, Decimal)>> {
+    cashflows(
+        schedule,
+        |period| rates.rate_on(period.accrual_start()),
+        notional,
+        *day_count,
+        amortization,
+    )
+}
+
+/// Nets the cashflows of the fixed and floating legs of a swap per payment date, returning
+/// them in chronological order.
+///
+/// The amounts are summed, so the legs are expected to be signed from the same side (e.g.
+/// received amounts positive and paid amounts negative). Cashflows falling on the same day are
+/// netted, while dates present in only one leg are carried through unchanged.
+///
+/// The payment dates are matched exactly, with no tolerance. Legs paying on the same date are
+/// generated with the same calendar and payment convention, so their adjusted dates coincide,
+/// while amounts paid on different days settle separately and cannot be netted, even if the
+/// days are close. Legs on different calendars should have their payment dates aligned before
+/// netting.
+#[must_use]
+pub fn net_cashflows(
+    fixed: &[(NaiveDate, Decimal)],
+    floating: &[(NaiveDate, Decimal)],
+) -> Vec<(NaiveDate, Decimal)> {
+    let mut net: BTreeMap<NaiveDate, Decimal> = BTreeMap::new();
+    for (date, amount) in fixed.iter().chain(floating) {
+        *net.entry(*date).or_default() += *amount;
+    }
+
+    net.into_iter().collect()
+}
+
+/// Returns the coupon and principal cashflows of the schedule's periods, using the rate
+/// returned by `rate` for each period.
+fn cashflows(
+    schedule: &Schedule,
+    rate: impl Fn(&CouponPeriod) -> Option<Decimal>,
+    notional: Decimal,
+    day_count: DayCountConvention,
+    amortization: &AmortizationSchedule,
+) -> Option<Vec<(NaiveDate, Decimal)>> {
+    let periods = schedule.periods();
+    let notionals = amortization.notionals(notional, periods.len())?;
+    let repayments = amortization.repayments(notional, periods.len())?;
+
+    periods
+        .iter()
+        .zip(notionals.iter().zip(repayments))
+        .map(|(period, (outstanding, repayment))| {
+            let fraction = day_count.year_fraction(period.accrual_start(), period.accrual_end())?;
+            Some((
+                period.payment_date(),
+                outstanding * rate(period)? * fraction + repayment,
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal::{prelude::*, Decimal};
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        calendar::weekend::AlwaysBusinessCalendar,
+        fixed_income::calculation_schedule::{Frequency, ScheduleBuilder},
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn assert_close(actual: Decimal, expected: f64) {
+        let actual = actual.to_f64().unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-8,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn xirr_matches_excel_reference() {
+        // The example from the Excel `XIRR` documentation.
+        let cashflows = [
+            (date(2008, 1, 1), dec!(-10000)),
+            (date(2008, 3, 1), dec!(2750)),
+            (date(2008, 10, 30), dec!(4250)),
+            (date(2009, 2, 15), dec!(3250)),
+            (date(2009, 4, 1), dec!(2750)),
+        ];
+
+        let rate = xirr(&cashflows, &DayCountConvention::Actual365Fixed, dec!(0.1)).unwrap();
+        assert_close(rate, 0.373_362_535_4);
+    }
+
+    #[test]
+    fn xirr_single_period() {
+        // 2020 has 366 days, so the return is annualised over 366/365 years.
+        let cashflows = [
+            (date(2020, 1, 1), dec!(-1000)),
+            (date(2021, 1, 1), dec!(1100)),
+        ];
+
+        let rate = xirr(&cashflows, &DayCountConvention::Actual365Fixed, dec!(0.1)).unwrap();
+        assert_close(rate, 1.1_f64.powf(365.0 / 366.0) - 1.0);
+    }
+
+    #[test]
+    fn xirr_negative_rate() {
+        let cashflows = [
+            (date(2021, 1, 1), dec!(-1000)),
+            (date(2022, 1, 1), dec!(900)),
+        ];
+
+        let rate = xirr(&cashflows, &DayCountConvention::Actual365Fixed, dec!(0.1)).unwrap();
+        assert_close(rate, -0.1);
+    }
+
+    #[test]
+    fn xirr_requires_sign_change() {
+        let positive = [
+            (date(2021, 1, 1), dec!(1000)),
+            (date(2022, 1, 1), dec!(900)),
+        ];
+        let negative = [
+            (date(2021, 1, 1), dec!(-1000)),
+            (date(2022, 1, 1), dec!(-900)),
+        ];
+
+        assert_eq!(
+            xirr(&positive, &DayCountConvention::Actual365Fixed, dec!(0.1)),
+            None
+        );
+        assert_eq!(
+            xirr(&negative, &DayCountConvention::Actual365Fixed, dec!(0.1)),
+            None
+        );
+        assert_eq!(
+            xirr(&[], &DayCountConvention::Actual365Fixed, dec!(0.1)),
+            None
+        );
+    }
+
+    #[test]
+    fn xirr_rejects_cashflow_before_first() {
+        let cashflows = [
+            (date(2022, 1, 1), dec!(-1000)),
+            (date(2021, 1, 1), dec!(1100)),
+        ];
+
+        assert_eq!(
+            xirr(&cashflows, &DayCountConvention::Actual365Fixed, dec!(0.1)),
+            None
+        );
+    }
+
+    fn annual_schedule(years: i32) -> Schedule {
+        ScheduleBuilder::new(
+            date(2023, 1, 1),
+            date(2023 + years, 1, 1),
+            Frequency::Annual,
+        )
+        .build(&AlwaysBusinessCalendar)
+        .unwrap()
+    }
+
+    #[test]
+    fn fixed_rate_cashflows_amortize_linearly() {
+        let cashflows = fixed_rate_cashflows(
+            &annual_schedule(4),
+            dec!(0.04),
+            dec!(1000),
+            &DayCountConvention::Thirty360,
+            &AmortizationSchedule::Linear,
+        )
+        .unwrap();
+
+        assert_eq!(
+            cashflows,
+            vec![
+                (date(2024, 1, 1), dec!(290)),
+                (date(2025, 1, 1), dec!(280)),
+                (date(2026, 1, 1), dec!(270)),
+                (date(2027, 1, 1), dec!(260)),
+            ]
+        );
+    }
+
+    #[test]
+    fn stepped_rate_cashflows_step_up_halfway() {
+        let rates = CouponRateSchedule::new(vec![
+            (date(2023, 1, 1), date(2025, 1, 1), dec!(0.03)),
+            (date(2025, 1, 1), date(2027, 1, 1), dec!(0.05)),
+        ])
+        .unwrap();
+
+        let cashflows = stepped_rate_cashflows(
+            &annual_schedule(4),
+            &rates,
+            dec!(1000),
+            &DayCountConvention::Thirty360,
+            &AmortizationSchedule::Bullet,
+        )
+        .unwrap();
+
+        assert_eq!(
+            cashflows,
+            vec![
+                (date(2024, 1, 1), dec!(30)),
+                (date(2025, 1, 1), dec!(30)),
+                (date(2026, 1, 1), dec!(50)),
+                (date(2027, 1, 1), dec!(1050)),
+            ]
+        );
+    }
+
+    #[test]
+    fn stepped_rate_cashflows_outside_rate_schedule() {
+        let rates = CouponRateSchedule::new(vec![(date(2023, 1, 1), date(2025, 1, 1), dec!(0.03))])
+            .unwrap();
+
+        assert_eq!(
+            stepped_rate_cashflows(
+                &annual_schedule(4),
+                &rates,
+                dec!(1000),
+                &DayCountConvention::Thirty360,
+                &AmortizationSchedule::Bullet,
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn net_cashflows_aligned_semiannual_legs() {
+        let fixed = [
+            (date(2024, 7, 15), dec!(2000)),
+            (date(2025, 1, 15), dec!(2000)),
+        ];
+        let floating = [
+            (date(2024, 7, 15), dec!(-1850.25)),
+            (date(2025, 1, 15), dec!(-2100.50)),
+        ];
+
+        assert_eq!(
+            net_cashflows(&fixed, &floating),
+            [
+                (date(2024, 7, 15), dec!(149.75)),
+                (date(2025, 1, 15), dec!(-100.50)),
+            ]
+        );
+    }
+
+    #[test]
+    fn net_cashflows_mismatched_payment_date() {
+        // The second floating payment is one day later, e.g. on a different calendar, so it is
+        // not netted with the fixed payment.
+        let fixed = [
+            (date(2024, 7, 15), dec!(2000)),
+            (date(2025, 1, 15), dec!(2000)),
+        ];
+        let floating = [
+            (date(2025, 1, 16), dec!(-2100.50)),
+            (date(2024, 7, 15), dec!(-1850.25)),
+        ];
+
+        assert_eq!(
+            net_cashflows(&fixed, &floating),
+            [
+                (date(2024, 7, 15), dec!(149.75)),
+                (date(2025, 1, 15), dec!(2000)),
+                (date(2025, 1, 16), dec!(-2100.50)),
+            ]
+        );
+        assert_eq!(net_cashflows(&fixed, &[]), fixed);
+    }
+
+    #[test]
+    fn zero_coupon_pays_only_redemption() {
+        let schedule = ScheduleBuilder::new(date(2025, 1, 15), date(2028, 1, 15), Frequency::Once)
+            .build(&AlwaysBusinessCalendar)
+            .unwrap();
+
+        assert_eq!(
+            fixed_rate_cashflows(
+                &schedule,
+                Decimal::ZERO,
+                dec!(1000000),
+                &DayCountConvention::Actual365Fixed,
+                &AmortizationSchedule::Bullet,
+            ),
+            Some(vec![(date(2028, 1, 15), dec!(1000000))])
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! This module provides the compounding of interest rates.
+//!
+//! The powers are calculated with [`MathematicalOps`], in decimal arithmetic rather than `f64`.
+//! An integer number of compounding periods is raised by repeated multiplication, while a
+//! fractional number of periods uses `exp(n * ln(1 + x))`; both are accurate to about 25 decimal
+//! places for the rates and horizons used in practice. The growth factor must fit into the range
+//! of [`Decimal`] (about `7.9e28`), e.g. 10% compounded annually overflows after 700 years, in
+//! which case `None` is returned. Very small discount factors keep only the 28 decimal places
+//! of [`Decimal`], so lose their significant digits.
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+/// Returns the interest accrued per unit of notional at the `rate` compounded `frequency` times
+/// a year over the given number of `years`, i.e. `(1 + rate / frequency) ^ (frequency * years) - 1`.
+///
+/// Returns `None` if the frequency is zero, the years are negative, the rate is not above
+/// `-frequency` or the calculation overflows.
+#[must_use]
+pub fn compounded_rate(rate: Decimal, frequency: u32, years: Decimal) -> Option<Decimal> {
+    compound_factor(rate, frequency, years)?.checked_sub(Decimal::ONE)
+}
+
+/// Returns the discount factor of the `rate` compounded `frequency` times a year over the given
+/// number of `years`, i.e. `(1 + rate / frequency) ^ -(frequency * years)`.
+///
+/// Returns `None` if the frequency is zero, the years are negative, the rate is not above
+/// `-frequency` or the calculation overflows.
+#[must_use]
+pub fn compound_discount_factor(rate: Decimal, frequency: u32, years: Decimal) -> Option<Decimal> {
+    Decimal::ONE.checked_div(compound_factor(rate, frequency, years)?)
+}
+
+/// Returns the growth factor `(1 + rate / frequency) ^ (frequency * years)`.
+fn compound_factor(rate: Decimal, frequency: u32, years: Decimal) -> Option<Decimal> {
+    if frequency == 0 || years.is_sign_negative() {
+        return None;
+    }
+
+    let frequency = Decimal::from(frequency);
+    let base = Decimal::ONE.checked_add(rate.checked_div(frequency)?)?;
+    if base <= Decimal::ZERO {
+        return None;
+    }
+
+    base.checked_powd(frequency.checked_mul(years)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    /// Asserts that the value agrees with the reference, calculated with 60 significant digits,
+    /// to 20 decimal places.
+    fn assert_close(value: Option<Decimal>, reference: Decimal) {
+        let value = value.unwrap();
+        assert!(
+            (value - reference).abs() < dec!(1e-20),
+            "{value} != {reference}"
+        );
+    }
+
+    #[test]
+    fn integer_powers_match_reference() {
+        assert_close(
+            compounded_rate(dec!(0.05), 12, dec!(10)),
+            dec!(0.6470094976902830341856736543),
+        );
+        assert_close(
+            compound_discount_factor(dec!(0.05), 2, dec!(10)),
+            dec!(0.6102709428588297633717199417),
+        );
+        assert_close(
+            compound_discount_factor(dec!(0.04), 4, dec!(0.75)),
+            dec!(0.9705901479276444456522899619),
+        );
+    }
+
+    #[test]
+    fn fractional_powers_match_reference() {
+        assert_close(
+            compounded_rate(dec!(0.03), 1, dec!(2.5)),
+            dec!(0.0766959061406335634289214994),
+        );
+        assert_close(
+            compound_discount_factor(dec!(0.04), 4, dec!(0.5)),
+            dec!(0.9802960494069208901088128615),
+        );
+    }
+
+    #[test]
+    fn zero_years_and_zero_rate() {
+        assert_eq!(compounded_rate(dec!(0.05), 4, dec!(0)), Some(dec!(0)));
+        assert_eq!(
+            compound_discount_factor(dec!(0), 4, dec!(10)),
+            Some(dec!(1))
+        );
+    }
+
+    #[test]
+    fn invalid_inputs_and_overflow() {
+        assert_eq!(compounded_rate(dec!(0.05), 0, dec!(1)), None);
+        assert_eq!(compounded_rate(dec!(0.05), 1, dec!(-1)), None);
+        assert_eq!(compounded_rate(dec!(-2), 2, dec!(1)), None);
+
+        // `1.1 ^ 700` is about `9.4e28`, beyond the range of `Decimal`.
+        assert!(compounded_rate(dec!(0.1), 1, dec!(600)).is_some());
+        assert_eq!(compounded_rate(dec!(0.1), 1, dec!(700)), None);
+        assert_eq!(compound_discount_factor(dec!(0.1), 1, dec!(700)), None);
+    }
+}
@@ -0,0 +1,318 @@
+//! This module provides the calculation of the coupons of a fixed rate schedule.
+
+use std::collections::HashMap;
+
+use rust_decimal::{Decimal, RoundingStrategy};
+
+use crate::fixed_income::{
+    calculation_schedule::{CouponPeriod, Schedule},
+    day_count_fraction::DayCountConvention,
+};
+
+/// A `Coupon` represents the coupon paid for a calculation period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Coupon {
+    /// The calculation period.
+    pub period: CouponPeriod,
+    /// The day count fraction of the period.
+    pub day_count_fraction: Decimal,
+    /// The coupon amount, i.e. the notional multiplied by the rate and the day count fraction,
+    /// rounded if the calculator has a rounding set.
+    pub amount: Decimal,
+}
+
+/// A `CouponCalculator` calculates the coupons paid at a fixed rate on a notional over the
+/// periods of a schedule.
+#[derive(Debug, Clone)]
+pub struct CouponCalculator {
+    schedule: Schedule,
+    day_count: DayCountConvention,
+    rate: Decimal,
+    notional: Decimal,
+    day_count_overrides: HashMap<usize, DayCountConvention>,
+    rounding: Option<u32>,
+}
+
+impl CouponCalculator {
+    /// Creates a calculator paying the `rate` on the `notional` over the periods of the
+    /// `schedule`, using the `day_count` convention supplied.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        day_count: DayCountConvention,
+        rate: Decimal,
+        notional: Decimal,
+    ) -> Self {
+        CouponCalculator {
+            schedule,
+            day_count,
+            rate,
+            notional,
+            day_count_overrides: HashMap::new(),
+            rounding: None,
+        }
+    }
+
+    /// Sets the day count convention used for the period at the `index` of the schedule
+    /// instead of the calculator's one, e.g. `0` for a front stub accruing with a different
+    /// convention than the regular periods.
+    #[must_use]
+    pub fn day_count_override(mut self, index: usize, day_count: DayCountConvention) -> Self {
+        self.day_count_overrides.insert(index, day_count);
+        self
+    }
+
+    /// Sets the number of decimal places the coupon amounts are rounded to, half away from
+    /// zero, e.g. the minor unit of the payment currency (see [`minor_units`]). The amounts
+    /// are not rounded by default.
+    ///
+    /// [`minor_units`]: crate::conventions::minor_units
+    #[must_use]
+    pub fn rounding(mut self, decimal_places: u32) -> Self {
+        self.rounding = Some(decimal_places);
+        self
+    }
+
+    /// Returns the schedule.
+    #[must_use]
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the day count convention.
+    #[must_use]
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    /// Returns the day count convention used for the period at the `index` of the schedule,
+    /// i.e. its override if set, or the calculator's convention.
+    #[must_use]
+    pub fn period_day_count(&self, index: usize) -> DayCountConvention {
+        self.day_count_overrides
+            .get(&index)
+            .copied()
+            .unwrap_or(self.day_count)
+    }
+
+    /// Returns the coupon rate.
+    #[must_use]
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns the notional.
+    #[must_use]
+    pub fn notional(&self) -> Decimal {
+        self.notional
+    }
+
+    /// Returns the coupon of every period of the schedule, in chronological order, each
+    /// accruing with its [`period_day_count`].
+    ///
+    /// Returns `None` if a day count fraction cannot be calculated with the convention (see
+    /// [`DayCountConvention::year_fraction`]).
+    ///
+    /// [`period_day_count`]: CouponCalculator::period_day_count
+    #[must_use]
+    pub fn coupons(&self) -> Option<Vec<Coupon>> {
+        self.schedule
+            .periods()
+            .iter()
+            .enumerate()
+            .map(|(index, period)| {
+                let day_count_fraction = self
+                    .period_day_count(index)
+                    .year_fraction(period.accrual_start(), period.accrual_end())?;
+                let mut amount = self.notional * self.rate * day_count_fraction;
+                if let Some(decimal_places) = self.rounding {
+                    amount = amount.round_dp_with_strategy(
+                        decimal_places,
+                        RoundingStrategy::MidpointAwayFromZero,
+                    );
+                }
+                Some(Coupon {
+                    period: *period,
+                    day_count_fraction,
+                    amount,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        calendar::target::TargetCalendar,
+        conventions::minor_units,
+        fixed_income::calculation_schedule::{Frequency, Generation, ScheduleBuilder},
+        BusinessDayConvetion,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A regular semiannual schedule from `start` to `end`, adjusted on the TARGET calendar.
+    fn semiannual(start: NaiveDate, end: NaiveDate, convention: BusinessDayConvetion) -> Schedule {
+        ScheduleBuilder::new(start, end, Frequency::SemiAnnual)
+            .convention(convention)
+            .build(&TargetCalendar)
+            .unwrap()
+    }
+
+    #[test]
+    fn regular_semiannual_coupons() {
+        let calculator = CouponCalculator::new(
+            semiannual(
+                date(2024, 1, 15),
+                date(2027, 1, 15),
+                BusinessDayConvetion::NoAdjustment,
+            ),
+            DayCountConvention::Thirty360,
+            dec!(0.04),
+            dec!(1000000),
+        );
+        let coupons = calculator.coupons().unwrap();
+
+        assert_eq!(coupons.len(), 6);
+        for coupon in &coupons {
+            assert_eq!(coupon.day_count_fraction, dec!(0.5));
+            assert_eq!(coupon.amount, dec!(20000));
+        }
+        assert_eq!(coupons[0].period.accrual_start(), date(2024, 1, 15));
+        assert_eq!(coupons[5].period.accrual_end(), date(2027, 1, 15));
+    }
+
+    #[test]
+    fn adjusted_semiannual_coupons_are_close_to_half_the_rate() {
+        // March 16th, 2024 is a Saturday and March 16th, 2025 a Sunday, so the periods
+        // around them are two and one days shorter or longer.
+        let calculator = CouponCalculator::new(
+            semiannual(
+                date(2024, 3, 16),
+                date(2026, 9, 16),
+                BusinessDayConvetion::ModifiedFollowiing,
+            ),
+            DayCountConvention::Thirty360,
+            dec!(0.04),
+            dec!(1000000),
+        );
+        let amounts: Vec<Decimal> = calculator
+            .coupons()
+            .unwrap()
+            .iter()
+            .map(|coupon| coupon.amount.round_dp(2))
+            .collect();
+
+        assert_eq!(
+            amounts,
+            [
+                dec!(19777.78),
+                dec!(20111.11),
+                dec!(19888.89),
+                dec!(20000),
+                dec!(20000),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsupported_day_count() {
+        let calculator = CouponCalculator::new(
+            semiannual(
+                date(2024, 1, 15),
+                date(2027, 1, 15),
+                BusinessDayConvetion::NoAdjustment,
+            ),
+            DayCountConvention::Business252,
+            dec!(0.04),
+            dec!(1000000),
+        );
+
+        assert_eq!(calculator.coupons(), None);
+    }
+
+    #[test]
+    fn front_stub_day_count_override() {
+        // A front stub from February 15th to April 15th, 2024, then regular quarters.
+        let schedule =
+            ScheduleBuilder::new(date(2024, 2, 15), date(2025, 1, 15), Frequency::Quarterly)
+                .generation(Generation::Backward)
+                .build(&TargetCalendar)
+                .unwrap();
+        let regular = CouponCalculator::new(
+            schedule,
+            DayCountConvention::Thirty360,
+            dec!(0.04),
+            dec!(1000000),
+        );
+        let overridden = regular
+            .clone()
+            .day_count_override(0, DayCountConvention::ActualActualIsda);
+
+        assert_eq!(
+            overridden.period_day_count(0),
+            DayCountConvention::ActualActualIsda
+        );
+        assert_eq!(
+            overridden.period_day_count(1),
+            DayCountConvention::Thirty360
+        );
+
+        let regular = regular.coupons().unwrap();
+        let overridden = overridden.coupons().unwrap();
+
+        // The 60 days of the stub are 60 / 360 under 30/360, but 60 / 366 in the leap year.
+        assert_eq!(regular[0].day_count_fraction, dec!(60) / dec!(360));
+        assert_eq!(overridden[0].day_count_fraction, dec!(60) / dec!(366));
+        assert_eq!(regular[0].amount.round_dp(2), dec!(6666.67));
+        assert_eq!(overridden[0].amount.round_dp(2), dec!(6557.38));
+        assert_eq!(regular[1..], overridden[1..]);
+    }
+
+    /// `ACT/360` coupons at the `rate` on the `notional` over the 182 and 184-day semiannual
+    /// periods from January 15th, 2024 to January 15th, 2025, rounded to the minor unit of the
+    /// `currency`.
+    fn rounded_coupons(rate: Decimal, notional: Decimal, currency: &str) -> Vec<Decimal> {
+        CouponCalculator::new(
+            semiannual(
+                date(2024, 1, 15),
+                date(2025, 1, 15),
+                BusinessDayConvetion::NoAdjustment,
+            ),
+            DayCountConvention::Actual360,
+            rate,
+            notional,
+        )
+        .rounding(minor_units(currency))
+        .coupons()
+        .unwrap()
+        .iter()
+        .map(|coupon| coupon.amount)
+        .collect()
+    }
+
+    #[test]
+    fn jpy_coupons_round_to_whole_yen() {
+        // 100,000,000 * 1.23% * 182 / 360 = 621,833.33 and * 184 / 360 = 628,666.67.
+        assert_eq!(
+            rounded_coupons(dec!(0.0123), dec!(100000000), "JPY"),
+            vec![dec!(621833), dec!(628667)]
+        );
+    }
+
+    #[test]
+    fn usd_coupons_round_to_cents() {
+        // 1,000,000 * 4% * 182 / 360 = 20,222.22 and * 184 / 360 = 20,444.44.
+        assert_eq!(
+            rounded_coupons(dec!(0.04), dec!(1000000), "USD"),
+            vec![dec!(20222.22), dec!(20444.44)]
+        );
+    }
+}
@@ -0,0 +1,151 @@
+//! This module provides the coupon rate schedules of step-up and step-down instruments.
+
+use std::{error::Error, fmt};
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+/// A `RateScheduleError` represents an invalid definition of a [`CouponRateSchedule`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateScheduleError {
+    /// No steps were supplied.
+    Empty,
+    /// A step does not end after it starts.
+    EmptyStep(NaiveDate),
+    /// A step starts before the previous step ends.
+    Overlap(NaiveDate),
+    /// A step starts after the previous step ends.
+    Gap(NaiveDate),
+}
+
+impl fmt::Display for RateScheduleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RateScheduleError::Empty => write!(f, "no coupon rate steps supplied"),
+            RateScheduleError::EmptyStep(start) => {
+                write!(
+                    f,
+                    "coupon rate step starting on {start} does not end after it starts"
+                )
+            }
+            RateScheduleError::Overlap(start) => {
+                write!(
+                    f,
+                    "coupon rate step starting on {start} overlaps the previous step"
+                )
+            }
+            RateScheduleError::Gap(start) => {
+                write!(
+                    f,
+                    "coupon rate step starting on {start} leaves a gap after the previous step"
+                )
+            }
+        }
+    }
+}
+
+impl Error for RateScheduleError {}
+
+/// A `CouponRateSchedule` maps consecutive date ranges to the coupon rates applicable in them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CouponRateSchedule {
+    steps: Vec<(NaiveDate, NaiveDate, Decimal)>,
+}
+
+impl CouponRateSchedule {
+    /// Creates a rate schedule from steps of `(start, end, rate)`, each applying from `start`
+    /// (inclusive) to `end` (exclusive). The steps are sorted by their start date.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no steps are supplied, a step does not end after it starts, or the
+    /// steps are not contiguous, i.e. a step overlaps the previous one or leaves a gap after it.
+    pub fn new(mut steps: Vec<(NaiveDate, NaiveDate, Decimal)>) -> Result<Self, RateScheduleError> {
+        if steps.is_empty() {
+            return Err(RateScheduleError::Empty);
+        }
+
+        steps.sort_by_key(|(start, _, _)| *start);
+        for (start, end, _) in &steps {
+            if start >= end {
+                return Err(RateScheduleError::EmptyStep(*start));
+            }
+        }
+        for window in steps.windows(2) {
+            let (_, previous_end, _) = window[0];
+            let (start, _, _) = window[1];
+            if start < previous_end {
+                return Err(RateScheduleError::Overlap(start));
+            }
+            if start > previous_end {
+                return Err(RateScheduleError::Gap(start));
+            }
+        }
+
+        Ok(CouponRateSchedule { steps })
+    }
+
+    /// Returns the rate applicable on the given date.
+    ///
+    /// Returns `None` if the date is outside the schedule.
+    #[must_use]
+    pub fn rate_on(&self, day: NaiveDate) -> Option<Decimal> {
+        self.steps
+            .iter()
+            .find(|(start, end, _)| *start <= day && day < *end)
+            .map(|(_, _, rate)| *rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn rate_on_selects_step() {
+        let rates = CouponRateSchedule::new(vec![
+            (date(2025, 1, 1), date(2027, 1, 1), dec!(0.05)),
+            (date(2023, 1, 1), date(2025, 1, 1), dec!(0.03)),
+        ])
+        .unwrap();
+
+        assert_eq!(rates.rate_on(date(2023, 1, 1)), Some(dec!(0.03)));
+        assert_eq!(rates.rate_on(date(2024, 12, 31)), Some(dec!(0.03)));
+        assert_eq!(rates.rate_on(date(2025, 1, 1)), Some(dec!(0.05)));
+        assert_eq!(rates.rate_on(date(2027, 1, 1)), None);
+        assert_eq!(rates.rate_on(date(2022, 12, 31)), None);
+    }
+
+    #[test]
+    fn new_rejects_invalid_steps() {
+        assert_eq!(
+            CouponRateSchedule::new(vec![]),
+            Err(RateScheduleError::Empty)
+        );
+        assert_eq!(
+            CouponRateSchedule::new(vec![(date(2023, 1, 1), date(2023, 1, 1), dec!(0.03))]),
+            Err(RateScheduleError::EmptyStep(date(2023, 1, 1)))
+        );
+        assert_eq!(
+            CouponRateSchedule::new(vec![
+                (date(2023, 1, 1), date(2025, 1, 1), dec!(0.03)),
+                (date(2024, 7, 1), date(2027, 1, 1), dec!(0.05)),
+            ]),
+            Err(RateScheduleError::Overlap(date(2024, 7, 1)))
+        );
+        assert_eq!(
+            CouponRateSchedule::new(vec![
+                (date(2023, 1, 1), date(2025, 1, 1), dec!(0.03)),
+                (date(2025, 7, 1), date(2027, 1, 1), dec!(0.05)),
+            ]),
+            Err(RateScheduleError::Gap(date(2025, 7, 1)))
+        );
+    }
+}
@@ -1,15 +1,236 @@
 //! This module provides function for day count fraction calculation.
 
-use chrono::{Datelike, Duration, NaiveDate, Weekday};
-use rust_decimal::{prelude::*, Decimal};
+use std::{error::Error, fmt};
+
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
 use rust_decimal_macros::dec;
 
+use crate::{calendar::Business, fixed_income::period::Period, tenor::is_end_of_month};
+
 const NON_LEAP: Decimal = dec!(365);
 const LEAP: Decimal = dec!(366);
+const AVERAGE_YEAR: Decimal = dec!(365.25);
 const THREE_SIXTY: Decimal = dec!(360);
+const BUSINESS_DAYS: Decimal = dec!(252);
+
+/// The number of decimal places of the fractions formatted by [`year_fraction_str`].
+pub const YEAR_FRACTION_STR_DP: u32 = 12;
+
+/// A `DayCountConvention` represents the method used to calculate the fraction
+/// of a year between two dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DayCountConvention {
+    /// `ACT/360`, see [`act_360`].
+    Actual360,
+    /// `ACT/365 (Fixed)`, see [`act_365f`].
+    Actual365Fixed,
+    /// `ACT/365.25`, see [`act_365_25`].
+    Actual365_25,
+    /// `ACT/ACT (ISDA)`, see [`act_act_isda`].
+    ActualActualIsda,
+    /// `ACT/ACT (ICMA)`, see [`act_act_isma`].
+    ActualActualIcma,
+    /// `30/360`, see [`d30_360`].
+    Thirty360,
+    /// `30E/360`, see [`d30e_360`].
+    ThirtyE360,
+    /// `30/360 German`, see [`d30_360_german`].
+    Thirty360German,
+    /// `30/365`, see [`d30_365`].
+    Thirty365,
+    /// `BUS/252`, see [`bus_252`].
+    Business252,
+}
+
+/// A `DayCountError` represents a failure to calculate a day count fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DayCountError {
+    /// The start date is after the end date.
+    StartAfterEnd,
+    /// The fraction was rounded to fewer decimal places than required.
+    PrecisionLoss {
+        /// The number of decimal places kept.
+        decimal_places: u32,
+    },
+}
+
+impl fmt::Display for DayCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DayCountError::StartAfterEnd => write!(f, "start date is after the end date"),
+            DayCountError::PrecisionLoss { decimal_places } => write!(
+                f,
+                "day count fraction rounded to {decimal_places} decimal places"
+            ),
+        }
+    }
+}
+
+impl Error for DayCountError {}
+
+/// An `EndInclusivity` represents whether the end date of a period is counted as one of its
+/// days by the actual day count conventions.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EndInclusivity {
+    /// The days from `start` (inclusive) to `end` (exclusive) are counted, the market standard.
+    #[default]
+    Exclusive,
+    /// The days from `start` to `end`, both inclusive, are counted, i.e. one more day.
+    Inclusive,
+}
+
+impl DayCountConvention {
+    /// Returns the day count fraction for the dates provided using the convention.
+    ///
+    /// Returns `None` if `start` is after `end`, or the convention requires a calendar or the
+    /// coupon period (see [`requires_calendar`] and [`requires_period`]).
+    ///
+    /// [`requires_calendar`]: DayCountConvention::requires_calendar
+    /// [`requires_period`]: DayCountConvention::requires_period
+    #[must_use]
+    pub fn year_fraction(&self, start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
+        match self {
+            DayCountConvention::Actual360 => act_360(start, end),
+            DayCountConvention::Actual365Fixed => act_365f(start, end),
+            DayCountConvention::Actual365_25 => act_365_25(start, end),
+            DayCountConvention::ActualActualIsda => act_act_isda(start, end),
+            DayCountConvention::Thirty360 => d30_360(start, end),
+            DayCountConvention::ThirtyE360 => d30e_360(start, end),
+            DayCountConvention::Thirty360German => d30_360_german(start, end),
+            DayCountConvention::Thirty365 => d30_365(start, end),
+            DayCountConvention::ActualActualIcma | DayCountConvention::Business252 => None,
+        }
+    }
+
+    /// Returns the day count fraction for the dates provided using the convention, converted to
+    /// `f64` for numerical code such as rate solvers.
+    ///
+    /// The conversion keeps about 16 significant digits of the 28 of [`Decimal`], so the result
+    /// should not be used for coupon amounts or compared for equality.
+    ///
+    /// Returns `None` under the same conditions as [`DayCountConvention::year_fraction`].
+    #[must_use]
+    pub fn year_fraction_f64(&self, start: NaiveDate, end: NaiveDate) -> Option<f64> {
+        self.year_fraction(start, end)?.to_f64()
+    }
+
+    /// Returns the day count fraction for the dates provided using the convention, rounded to
+    /// `dp` decimal places with the rounding `strategy` supplied (e.g.
+    /// [`RoundingStrategy::MidpointNearestEven`] for banker's rounding).
+    ///
+    /// Returns `None` under the same conditions as [`DayCountConvention::year_fraction`].
+    #[must_use]
+    pub fn year_fraction_rounded(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        dp: u32,
+        strategy: RoundingStrategy,
+    ) -> Option<Decimal> {
+        Some(
+            self.year_fraction(start, end)?
+                .round_dp_with_strategy(dp, strategy),
+        )
+    }
+
+    /// Returns the day count fraction for the dates provided using the convention, like
+    /// [`DayCountConvention::year_fraction`], counting the end date as one of the days of the
+    /// period if the `inclusivity` is [`EndInclusivity::Inclusive`].
+    ///
+    /// The inclusivity applies to the actual day count conventions (`ACT/360`, `ACT/365 (Fixed)`,
+    /// `ACT/365.25` and `ACT/ACT (ISDA)`); the others ignore it.
+    ///
+    /// Returns `None` under the same conditions as [`DayCountConvention::year_fraction`], or if
+    /// the day after `end` is out of range.
+    #[must_use]
+    pub fn year_fraction_with_end(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        inclusivity: EndInclusivity,
+    ) -> Option<Decimal> {
+        let actual = matches!(
+            self,
+            DayCountConvention::Actual360
+                | DayCountConvention::Actual365Fixed
+                | DayCountConvention::Actual365_25
+                | DayCountConvention::ActualActualIsda
+        );
+
+        match inclusivity {
+            EndInclusivity::Inclusive if actual => {
+                if start > end {
+                    return None;
+                }
+                self.year_fraction(start, end.succ_opt()?)
+            }
+            _ => self.year_fraction(start, end),
+        }
+    }
+
+    /// Returns the number of days between the dates counted by the convention, i.e. the
+    /// numerator of the day count fraction: the actual days for the `ACT` conventions and the
+    /// days assuming 30-day months for the `30` conventions.
+    ///
+    /// Returns `None` if `start` is after `end`, or the convention requires a calendar (see
+    /// [`requires_calendar`]).
+    ///
+    /// [`requires_calendar`]: DayCountConvention::requires_calendar
+    #[must_use]
+    pub fn day_count(&self, start: NaiveDate, end: NaiveDate) -> Option<i64> {
+        if start > end {
+            return None;
+        }
 
+        match self {
+            DayCountConvention::Actual360
+            | DayCountConvention::Actual365Fixed
+            | DayCountConvention::Actual365_25
+            | DayCountConvention::ActualActualIsda
+            | DayCountConvention::ActualActualIcma => Some((end - start).num_days()),
+            DayCountConvention::Thirty360 | DayCountConvention::Thirty365 => {
+                Some(d30_360_day_count(start, end))
+            }
+            DayCountConvention::ThirtyE360 => Some(d30_day_count(
+                start,
+                end,
+                start.day().min(30),
+                end.day().min(30),
+            )),
+            DayCountConvention::Thirty360German => Some(d30_day_count(
+                start,
+                end,
+                german_day(start),
+                german_day(end),
+            )),
+            DayCountConvention::Business252 => None,
+        }
+    }
+
+    /// Checks whether the convention requires a business calendar to calculate the
+    /// day count fraction.
+    #[must_use]
+    pub fn requires_calendar(&self) -> bool {
+        matches!(self, DayCountConvention::Business252)
+    }
+
+    /// Checks whether the convention requires the coupon period and frequency to calculate
+    /// the day count fraction.
+    #[must_use]
+    pub fn requires_period(&self) -> bool {
+        matches!(self, DayCountConvention::ActualActualIcma)
+    }
+}
 
 /// Returns an `ACT/360` day count fraction for the dates provided.
+///
+/// [`Decimal`] holds 28 significant digits, so a fraction that cannot be represented exactly
+/// (e.g. `1 / 360`) is rounded to `28 - n` decimal places, where `n` is the number of digits
+/// of its integer part. Over the whole range of [`NaiveDate`] the fraction is below `532 000`,
+/// so at least 22 decimal places are kept; see [`act_360_checked`] to enforce a bound.
+#[must_use]
 pub fn act_360(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
     if start > end {
         return None;
@@ -18,7 +239,38 @@ pub fn act_360(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
     Some(Decimal::new((end - start).num_days(), 0) / THREE_SIXTY)
 }
 
+/// Returns an `ACT/360` day count fraction for the dates provided, like [`act_360`], checking
+/// that a fraction rounded by the division keeps at least `decimal_places` decimal places.
+///
+/// # Errors
+///
+/// Returns [`DayCountError::StartAfterEnd`] if `start` is after `end`, or
+/// [`DayCountError::PrecisionLoss`] if the rounded fraction has fewer decimal places.
+pub fn act_360_checked(
+    start: NaiveDate,
+    end: NaiveDate,
+    decimal_places: u32,
+) -> Result<Decimal, DayCountError> {
+    if start > end {
+        return Err(DayCountError::StartAfterEnd);
+    }
+
+    let days = (end - start).num_days();
+    let fraction = Decimal::new(days, 0) / THREE_SIXTY;
+
+    // As `360 = 2^3 * 3^2 * 5`, the fraction has a finite decimal expansion, and so is exact,
+    // if and only if the number of days is a multiple of 9.
+    if days % 9 != 0 && fraction.scale() < decimal_places {
+        return Err(DayCountError::PrecisionLoss {
+            decimal_places: fraction.scale(),
+        });
+    }
+
+    Ok(fraction)
+}
+
 /// Returns an `ACT/365 (Fixed)` day count fraction for the dates provided.
+#[must_use]
 pub fn act_365f(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
     if start > end {
         return None;
@@ -27,62 +279,126 @@ pub fn act_365f(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
     Some(Decimal::new((end - start).num_days(), 0) / NON_LEAP)
 }
 
-/// Returns an `ACT/ACT (ISDA)` day count fraction
+/// Returns an `ACT/365.25` day count fraction for the dates provided.
+#[must_use]
+pub fn act_365_25(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
+    if start > end {
+        return None;
+    }
+
+    Some(Decimal::new((end - start).num_days(), 0) / AVERAGE_YEAR)
+}
+
+/// Returns an `ACT/ACT (ISDA)` day count fraction for the dates provided.
+///
+/// The days in each calendar year of the period, counting `start` but not `end`, are divided by
+/// the length of that year, 365 or 366 days, so every whole calendar year counts as exactly one,
+/// e.g. from November 1st, 2003 to May 1st, 2004 is `61 / 365 + 121 / 366 = 0.497724380567...`.
+///
+/// The calculation is exact over the whole range of [`NaiveDate`], including the years before
+/// the common era, which follow the proleptic Gregorian calendar (e.g. year 0 is a leap year).
+#[must_use]
 pub fn act_act_isda(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
     if start > end {
         return None;
     }
-    let mut year = start.year();
+    let year = start.year();
 
     if year == end.year() {
         if is_leap(year) {
             return Some(Decimal::new((end - start).num_days(), 0) / LEAP);
         }
-        return Some(Decimal::new((end - start).num_days(), 0) / NON_LEAP);        
+        return Some(Decimal::new((end - start).num_days(), 0) / NON_LEAP);
     }
 
+    // The first year's stub counts the days from `start` up to (excluding) January 1st
+    // of the following year, i.e. including `start` itself.
     let mut dcf = if is_leap(year) {
-        Decimal::new(366 - i64::from(start.ordinal()), 0) / LEAP
+        Decimal::new(367 - i64::from(start.ordinal()), 0) / LEAP
     } else {
-        Decimal::new(365 - i64::from(start.ordinal()), 0) / NON_LEAP
+        Decimal::new(366 - i64::from(start.ordinal()), 0) / NON_LEAP
     };
-    
-
-    year += 1;  
 
+    // Every full calendar year in between counts as exactly one.
+    dcf += Decimal::from(end.year() - year - 1);
 
-    while year < end.year() {
-        dcf += Decimal::new(1, 0);
-        year += 1;
-    }
-
-    if is_leap(year) {
-        dcf += Decimal::new(i64::from(end.ordinal()), 0) / LEAP;
+    // The last year's stub counts the days from January 1st up to (excluding) `end`.
+    if is_leap(end.year()) {
+        dcf += Decimal::new(i64::from(end.ordinal()) - 1, 0) / LEAP;
     } else {
-        dcf += Decimal::new(i64::from(end.ordinal()), 0) / NON_LEAP;
-    };
+        dcf += Decimal::new(i64::from(end.ordinal()) - 1, 0) / NON_LEAP;
+    }
 
     Some(dcf)
 }
 
 /// Calculates `ACT/ACT (ICMA)` day count fraction for the given dates.
-pub fn act_act_isma(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
-    if start > end {
+///
+/// The number of days between `start` and `end` is divided by the product of the number
+/// of days in the coupon period (`period_start` to `period_end`) and the number of coupon
+/// periods in a year (`frequency`).
+#[must_use]
+pub fn act_act_isma(
+    start: NaiveDate,
+    end: NaiveDate,
+    period_start: NaiveDate,
+    period_end: NaiveDate,
+    frequency: u32,
+) -> Option<Decimal> {
+    if start > end || period_start >= period_end || frequency == 0 {
         return None;
     }
+
+    let days = Decimal::new((end - start).num_days(), 0);
+    let period_days = Decimal::new((period_end - period_start).num_days(), 0);
+
+    Some(days / (period_days * Decimal::from(frequency)))
+}
+
+/// Returns a `BUS/252` day count fraction for the dates provided, i.e. the number of business
+/// days from `start` (inclusive) to `end` (exclusive) in the `calendar` divided by 252.
+#[must_use]
+pub fn bus_252<B: Business + ?Sized>(
+    start: NaiveDate,
+    end: NaiveDate,
+    calendar: &B,
+) -> Option<Decimal> {
+    let days = calendar.count_business_days(start, end)?;
+
+    Some(Decimal::from(days) / BUSINESS_DAYS)
 }
 
 /// Returns a `30/360` day count fraction for the given dates.
+#[must_use]
 pub fn d30_360(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
     if start > end {
         return None;
     }
 
-    let start_day = if start.day() == 31 {
-        30
-    } else {
-        start.day()
-    };
+    Some(Decimal::new(d30_360_day_count(start, end), 0) / THREE_SIXTY)
+}
+
+/// Returns a `30/360` day count fraction for the period, like [`d30_360`]. As the start of a
+/// [`Period`] is never after its end, the fraction can always be calculated.
+#[must_use]
+pub fn d30_360_period(period: Period) -> Decimal {
+    Decimal::new(d30_360_day_count(period.start(), period.end()), 0) / THREE_SIXTY
+}
+
+/// Returns a `30/365` day count fraction for the given dates, i.e. the days counted as in
+/// `30/360` divided by a 365-day year; e.g. a full year is `360 / 365`.
+#[must_use]
+pub fn d30_365(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
+    if start > end {
+        return None;
+    }
+
+    Some(Decimal::new(d30_360_day_count(start, end), 0) / NON_LEAP)
+}
+
+/// Returns the number of days between the dates under the `30/360` convention.
+fn d30_360_day_count(start: NaiveDate, end: NaiveDate) -> i64 {
+    let start_day = if start.day() == 31 { 30 } else { start.day() };
 
     let end_day = if end.day() == 31 && start_day == 30 {
         30
@@ -90,17 +406,795 @@ pub fn d30_360(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
         end.day()
     };
 
-    let years = i64::from(end.year() - start.year());
-    let months = i64::from(end.month() - start.month());
-    let days = i64::from(end_day - start_day);
-    let day_count = years * 360 + months * 30 + days;
+    d30_day_count(start, end, start_day, end_day)
+}
+
+/// Returns a `30E/360` (Eurobond basis) day count fraction for the given dates.
+///
+/// Unlike `30/360`, the 31st day of the month is treated as the 30th for both dates,
+/// regardless of the start date.
+#[must_use]
+pub fn d30e_360(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
+    if start > end {
+        return None;
+    }
 
-    Some(Decimal::new(day_count, 0) / Decimal::new(360, 0))
+    let start_day = start.day().min(30);
+    let end_day = end.day().min(30);
 
+    Some(Decimal::new(d30_day_count(start, end, start_day, end_day), 0) / THREE_SIXTY)
 }
 
-/// Checks whether a year is a leap year.
+/// Returns the day count fraction for the dates provided using the `convention`, formatted
+/// with exactly [`YEAR_FRACTION_STR_DP`] decimal places, e.g. `"0.497724380567"` with
+/// `ACT/ACT (ISDA)` from November 1st, 2003 to May 1st, 2004.
+///
+/// The fraction is rounded half to even, so the string is the same on every platform and can
+/// be used in logs and golden files, and read back with [`parse_year_fraction`].
+///
+/// Returns `None` under the same conditions as [`DayCountConvention::year_fraction`].
+#[must_use]
+pub fn year_fraction_str(
+    start: NaiveDate,
+    end: NaiveDate,
+    convention: &DayCountConvention,
+) -> Option<String> {
+    let fraction = convention.year_fraction_rounded(
+        start,
+        end,
+        YEAR_FRACTION_STR_DP,
+        RoundingStrategy::MidpointNearestEven,
+    )?;
+
+    Some(format!("{fraction:.0$}", YEAR_FRACTION_STR_DP as usize))
+}
+
+/// Parses a day count fraction formatted by [`year_fraction_str`].
+///
+/// Returns `None` if the string is not a decimal number.
+#[must_use]
+pub fn parse_year_fraction(fraction: &str) -> Option<Decimal> {
+    fraction.parse().ok()
+}
+
+/// Returns a `30/360 German` day count fraction for the given dates.
+///
+/// Like `30E/360`, the 31st day of the month is treated as the 30th for both dates, and the
+/// last day of February (the 28th or the 29th) is treated as the 30th as well, for both dates,
+/// e.g. from February 28th to March 31st, 2023 is 30 days.
+#[must_use]
+pub fn d30_360_german(start: NaiveDate, end: NaiveDate) -> Option<Decimal> {
+    if start > end {
+        return None;
+    }
+
+    let days = d30_day_count(start, end, german_day(start), german_day(end));
+    Some(Decimal::new(days, 0) / THREE_SIXTY)
+}
+
+/// Returns the day of month of the date under the `30/360 German` convention.
+fn german_day(day: NaiveDate) -> u32 {
+    if day.month() == 2 && is_end_of_month(day) {
+        30
+    } else {
+        day.day().min(30)
+    }
+}
+
+/// Returns the number of days between the dates assuming 30-day months, using the
+/// already adjusted days of month. The arithmetic is signed, so it cannot underflow for any
+/// pair of dates, e.g. from the 31st to the 1st of the next month.
+fn d30_day_count(start: NaiveDate, end: NaiveDate, start_day: u32, end_day: u32) -> i64 {
+    let years = i64::from(end.year() - start.year());
+    let months = i64::from(end.month()) - i64::from(start.month());
+    let days = i64::from(end_day) - i64::from(start_day);
+
+    years * 360 + months * 30 + days
+}
+
+/// Checks whether a year is a leap year in the proleptic Gregorian calendar.
+///
+/// The remainders are only compared with zero, so this holds for negative years as well.
 fn is_leap(year: i32) -> bool {
     year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn act_act_isda_leap_year_end_to_jan_first() {
+        assert_eq!(
+            act_act_isda(date(2020, 12, 31), date(2021, 1, 1)),
+            Some(dec!(1) / LEAP)
+        );
+    }
+
+    #[test]
+    fn act_act_isda_non_leap_year_end_to_jan_first() {
+        assert_eq!(
+            act_act_isda(date(2019, 12, 31), date(2020, 1, 1)),
+            Some(dec!(1) / NON_LEAP)
+        );
+    }
+
+    /// Day count fraction vectors for the conventions of the ISDA 2006 Definitions, Section 4.16,
+    /// as `(start, end, convention, expected)`, with the expected fraction rounded to 12 decimal
+    /// places.
+    #[rustfmt::skip]
+    const ISDA_VECTORS: &[(&str, &str, DayCountConvention, &str)] = &[
+        // ACT/360: the actual days of the ACT/ACT examples divided by 360.
+        ("2003-11-01", "2004-05-01", DayCountConvention::Actual360, "0.505555555556"),
+        ("1999-02-01", "1999-07-01", DayCountConvention::Actual360, "0.416666666667"),
+        ("2002-08-15", "2003-07-15", DayCountConvention::Actual360, "0.927777777778"),
+        ("2003-07-15", "2004-01-15", DayCountConvention::Actual360, "0.511111111111"),
+        ("1999-07-30", "2000-01-30", DayCountConvention::Actual360, "0.511111111111"),
+        ("2000-01-30", "2000-06-30", DayCountConvention::Actual360, "0.422222222222"),
+
+        // ACT/365 (Fixed): the actual days of the ACT/ACT examples divided by 365.
+        ("2003-11-01", "2004-05-01", DayCountConvention::Actual365Fixed, "0.498630136986"),
+        ("1999-02-01", "1999-07-01", DayCountConvention::Actual365Fixed, "0.410958904110"),
+        ("2002-08-15", "2003-07-15", DayCountConvention::Actual365Fixed, "0.915068493151"),
+        ("2003-07-15", "2004-01-15", DayCountConvention::Actual365Fixed, "0.504109589041"),
+        ("1999-07-30", "2000-01-30", DayCountConvention::Actual365Fixed, "0.504109589041"),
+        ("2000-01-30", "2000-06-30", DayCountConvention::Actual365Fixed, "0.416438356164"),
+
+        // ACT/ACT (ISDA): the worked examples of the ISDA memorandum on the ACT/ACT conventions.
+        ("2003-11-01", "2004-05-01", DayCountConvention::ActualActualIsda, "0.497724380567"),
+        ("1999-02-01", "1999-07-01", DayCountConvention::ActualActualIsda, "0.410958904110"),
+        ("2002-08-15", "2003-07-15", DayCountConvention::ActualActualIsda, "0.915068493151"),
+        ("2003-07-15", "2004-01-15", DayCountConvention::ActualActualIsda, "0.504004790778"),
+        ("1999-07-30", "2000-01-30", DayCountConvention::ActualActualIsda, "0.503892506924"),
+        ("2000-01-30", "2000-06-30", DayCountConvention::ActualActualIsda, "0.415300546448"),
+
+        // 30/360: Section 4.16(f), the 31st at the end is kept unless the start is the 30th.
+        ("2007-01-15", "2007-01-30", DayCountConvention::Thirty360, "0.041666666667"),
+        ("2007-01-15", "2007-02-15", DayCountConvention::Thirty360, "0.083333333333"),
+        ("2007-01-15", "2007-07-15", DayCountConvention::Thirty360, "0.500000000000"),
+        ("2007-09-30", "2008-03-31", DayCountConvention::Thirty360, "0.500000000000"),
+        ("2007-09-30", "2007-10-31", DayCountConvention::Thirty360, "0.083333333333"),
+        ("2007-09-30", "2008-09-30", DayCountConvention::Thirty360, "1.000000000000"),
+        ("2007-01-15", "2007-01-31", DayCountConvention::Thirty360, "0.044444444444"),
+        ("2007-01-31", "2007-02-28", DayCountConvention::Thirty360, "0.077777777778"),
+        ("2007-02-28", "2007-03-31", DayCountConvention::Thirty360, "0.091666666667"),
+        ("2006-08-31", "2007-02-28", DayCountConvention::Thirty360, "0.494444444444"),
+        ("2007-02-28", "2007-08-31", DayCountConvention::Thirty360, "0.508333333333"),
+        ("2007-02-14", "2007-02-28", DayCountConvention::Thirty360, "0.038888888889"),
+        ("2007-02-26", "2008-02-29", DayCountConvention::Thirty360, "1.008333333333"),
+        ("2008-02-29", "2009-02-28", DayCountConvention::Thirty360, "0.997222222222"),
+        ("2008-02-29", "2008-03-30", DayCountConvention::Thirty360, "0.086111111111"),
+        ("2008-02-29", "2008-03-31", DayCountConvention::Thirty360, "0.088888888889"),
+        ("2007-02-28", "2007-03-05", DayCountConvention::Thirty360, "0.019444444444"),
+        ("2007-10-31", "2007-11-28", DayCountConvention::Thirty360, "0.077777777778"),
+        ("2007-08-31", "2008-02-29", DayCountConvention::Thirty360, "0.497222222222"),
+        ("2008-02-29", "2008-08-31", DayCountConvention::Thirty360, "0.505555555556"),
+        ("2008-08-31", "2009-02-28", DayCountConvention::Thirty360, "0.494444444444"),
+        ("2009-02-28", "2009-08-31", DayCountConvention::Thirty360, "0.508333333333"),
+
+        // 30E/360: Section 4.16(g), with the 31st treated as the 30th at both ends.
+        ("2007-01-15", "2007-01-30", DayCountConvention::ThirtyE360, "0.041666666667"),
+        ("2007-01-15", "2007-02-15", DayCountConvention::ThirtyE360, "0.083333333333"),
+        ("2007-01-15", "2007-07-15", DayCountConvention::ThirtyE360, "0.500000000000"),
+        ("2007-09-30", "2008-03-31", DayCountConvention::ThirtyE360, "0.500000000000"),
+        ("2007-09-30", "2007-10-31", DayCountConvention::ThirtyE360, "0.083333333333"),
+        ("2007-09-30", "2008-09-30", DayCountConvention::ThirtyE360, "1.000000000000"),
+        ("2007-01-15", "2007-01-31", DayCountConvention::ThirtyE360, "0.041666666667"),
+        ("2007-01-31", "2007-02-28", DayCountConvention::ThirtyE360, "0.077777777778"),
+        ("2007-02-28", "2007-03-31", DayCountConvention::ThirtyE360, "0.088888888889"),
+        ("2006-08-31", "2007-02-28", DayCountConvention::ThirtyE360, "0.494444444444"),
+        ("2007-02-28", "2007-08-31", DayCountConvention::ThirtyE360, "0.505555555556"),
+        ("2007-02-14", "2007-02-28", DayCountConvention::ThirtyE360, "0.038888888889"),
+        ("2007-02-26", "2008-02-29", DayCountConvention::ThirtyE360, "1.008333333333"),
+        ("2008-02-29", "2009-02-28", DayCountConvention::ThirtyE360, "0.997222222222"),
+        ("2008-02-29", "2008-03-30", DayCountConvention::ThirtyE360, "0.086111111111"),
+        ("2008-02-29", "2008-03-31", DayCountConvention::ThirtyE360, "0.086111111111"),
+        ("2007-02-28", "2007-03-05", DayCountConvention::ThirtyE360, "0.019444444444"),
+        ("2007-10-31", "2007-11-28", DayCountConvention::ThirtyE360, "0.077777777778"),
+        ("2007-08-31", "2008-02-29", DayCountConvention::ThirtyE360, "0.497222222222"),
+        ("2008-02-29", "2008-08-31", DayCountConvention::ThirtyE360, "0.502777777778"),
+        ("2008-08-31", "2009-02-28", DayCountConvention::ThirtyE360, "0.494444444444"),
+        ("2009-02-28", "2009-08-31", DayCountConvention::ThirtyE360, "0.505555555556"),
+    ];
+
+    /// `ACT/ACT (ICMA)` vectors from the same ISDA examples, as
+    /// `(start, end, period_start, period_end, frequency, expected)`.
+    #[rustfmt::skip]
+    const ICMA_VECTORS: &[(&str, &str, &str, &str, u32, &str)] = &[
+        ("2003-11-01", "2004-05-01", "2003-11-01", "2004-05-01", 2, "0.500000000000"),
+        ("1999-02-01", "1999-07-01", "1998-07-01", "1999-07-01", 1, "0.410958904110"),
+        ("2003-07-15", "2004-01-15", "2003-07-15", "2004-01-15", 2, "0.500000000000"),
+        ("1999-07-30", "2000-01-30", "1999-07-30", "2000-01-30", 2, "0.500000000000"),
+        ("2000-01-30", "2000-06-30", "2000-01-30", "2000-07-30", 2, "0.417582417582"),
+    ];
+
+    fn parse_date(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    fn assert_vector(start: &str, end: &str, label: &str, actual: Option<Decimal>, expected: &str) {
+        let actual = actual.map(|fraction| {
+            fraction.round_dp_with_strategy(12, RoundingStrategy::MidpointAwayFromZero)
+        });
+        assert_eq!(
+            actual,
+            Some(expected.parse().unwrap()),
+            "{label} from {start} to {end}"
+        );
+    }
+
+    #[test]
+    fn isda_2006_vectors() {
+        for (start, end, convention, expected) in ISDA_VECTORS {
+            let actual = convention.year_fraction(parse_date(start), parse_date(end));
+            assert_vector(start, end, &format!("{convention:?}"), actual, expected);
+        }
+    }
+
+    #[test]
+    fn isda_2006_icma_vectors() {
+        for (start, end, period_start, period_end, frequency, expected) in ICMA_VECTORS {
+            let actual = act_act_isma(
+                parse_date(start),
+                parse_date(end),
+                parse_date(period_start),
+                parse_date(period_end),
+                *frequency,
+            );
+            assert_vector(start, end, "ActualActualIcma", actual, expected);
+        }
+    }
+
+    #[test]
+    fn requires_calendar_and_period_flags() {
+        let flags = [
+            (DayCountConvention::Actual360, false, false),
+            (DayCountConvention::Actual365Fixed, false, false),
+            (DayCountConvention::Actual365_25, false, false),
+            (DayCountConvention::ActualActualIsda, false, false),
+            (DayCountConvention::ActualActualIcma, false, true),
+            (DayCountConvention::Thirty360, false, false),
+            (DayCountConvention::ThirtyE360, false, false),
+            (DayCountConvention::Thirty360German, false, false),
+            (DayCountConvention::Thirty365, false, false),
+            (DayCountConvention::Business252, true, false),
+        ];
+
+        for (convention, calendar, period) in flags {
+            assert_eq!(convention.requires_calendar(), calendar, "{convention:?}");
+            assert_eq!(convention.requires_period(), period, "{convention:?}");
+
+            // A convention needing more context cannot calculate the fraction from the dates.
+            let fraction = convention.year_fraction(date(2024, 1, 1), date(2024, 7, 1));
+            assert_eq!(fraction.is_none(), calendar || period, "{convention:?}");
+        }
+    }
+
+    #[test]
+    fn year_fraction_rounded_to_10_dp() {
+        let convention = DayCountConvention::ActualActualIsda;
+        let round =
+            |start, end, strategy| convention.year_fraction_rounded(start, end, 10, strategy);
+
+        // `61 / 365 + 121 / 366 = 0.49772438056|7...` rounds up with either strategy.
+        let (start, end) = (date(2003, 11, 1), date(2004, 5, 1));
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointNearestEven),
+            Some(dec!(0.4977243806))
+        );
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointAwayFromZero),
+            Some(dec!(0.4977243806))
+        );
+
+        // `1 / 365 = 0.00273972602|739...` rounds down with either strategy.
+        let (start, end) = (date(2003, 1, 1), date(2003, 1, 2));
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointNearestEven),
+            Some(dec!(0.0027397260))
+        );
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointAwayFromZero),
+            Some(dec!(0.0027397260))
+        );
+    }
+
+    #[test]
+    fn year_fraction_rounded_half_even_vs_half_up() {
+        // The denominators 365 and 366 never leave an ACT/ACT fraction exactly halfway at
+        // 10 decimal places, so the strategies differ on whole numbers of half years, e.g.
+        // `183 / 366 = 0.5` and `1 + 1 + 183 / 366 = 2.5`.
+        let convention = DayCountConvention::ActualActualIsda;
+        let round =
+            |start, end, strategy| convention.year_fraction_rounded(start, end, 0, strategy);
+
+        let (start, end) = (date(2020, 1, 1), date(2020, 7, 2));
+        assert_eq!(convention.year_fraction(start, end), Some(dec!(0.5)));
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointNearestEven),
+            Some(dec!(0))
+        );
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointAwayFromZero),
+            Some(dec!(1))
+        );
+
+        let (start, end) = (date(2022, 1, 1), date(2024, 7, 2));
+        assert_eq!(convention.year_fraction(start, end), Some(dec!(2.5)));
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointNearestEven),
+            Some(dec!(2))
+        );
+        assert_eq!(
+            round(start, end, RoundingStrategy::MidpointAwayFromZero),
+            Some(dec!(3))
+        );
+    }
+
+    #[test]
+    fn act_act_isda_over_whole_date_range() {
+        // `NaiveDate::MIN` is January 1st, so the first year counts as one whole year, and
+        // `NaiveDate::MAX` is December 31st of a non-leap year.
+        let years = NaiveDate::MAX.year() - NaiveDate::MIN.year();
+        assert_eq!(NaiveDate::MIN.ordinal(), 1);
+        assert_eq!(NaiveDate::MAX.ordinal(), 365);
+
+        assert_eq!(
+            act_act_isda(NaiveDate::MIN, NaiveDate::MAX),
+            Some(Decimal::from(years) + dec!(364) / NON_LEAP)
+        );
+        assert_eq!(years, 524_285);
+    }
+
+    #[test]
+    fn act_act_isda_before_common_era() {
+        // Year 0 is a leap year in the proleptic Gregorian calendar, while year -1 is not.
+        let year_zero = |month, day| NaiveDate::from_ymd_opt(0, month, day).unwrap();
+        let before = NaiveDate::from_ymd_opt(-1, 12, 31).unwrap();
+
+        assert_eq!(act_act_isda(year_zero(1, 1), date(1, 1, 1)), Some(dec!(1)));
+        assert_eq!(
+            act_act_isda(year_zero(2, 28), year_zero(3, 1)),
+            Some(dec!(2) / LEAP)
+        );
+        assert_eq!(
+            act_act_isda(before, year_zero(1, 1)),
+            Some(dec!(1) / NON_LEAP)
+        );
+        assert_eq!(
+            act_act_isda(
+                NaiveDate::from_ymd_opt(-401, 1, 1).unwrap(),
+                year_zero(1, 1)
+            ),
+            Some(dec!(401))
+        );
+    }
+
+    #[test]
+    fn is_leap_negative_years() {
+        assert!(is_leap(0));
+        assert!(is_leap(-4));
+        assert!(!is_leap(-1));
+        assert!(!is_leap(-100));
+        assert!(is_leap(-400));
+        assert!(!is_leap(1900));
+        assert!(is_leap(2000));
+    }
+
+    #[test]
+    fn act_365_25_vs_act_365f_over_four_years() {
+        // Four years including one leap year are exactly 1461 days.
+        let (start, end) = (date(2020, 1, 1), date(2024, 1, 1));
+
+        assert_eq!(act_365_25(start, end), Some(dec!(4)));
+        assert_eq!(act_365f(start, end), Some(dec!(1461) / NON_LEAP));
+        assert_eq!(
+            act_365f(start, end).unwrap() - act_365_25(start, end).unwrap(),
+            dec!(1) / NON_LEAP
+        );
+        assert_eq!(
+            DayCountConvention::Actual365_25.year_fraction(start, end),
+            act_365_25(start, end)
+        );
+    }
+
+    #[test]
+    fn act_365_25_rejects_reversed_dates() {
+        assert_eq!(act_365_25(date(2024, 1, 2), date(2024, 1, 1)), None);
+        assert_eq!(
+            act_365_25(date(2024, 1, 1), date(2024, 1, 1)),
+            Some(Decimal::ZERO)
+        );
+    }
+
+    #[test]
+    fn act_act_isda_leap_year_first_stub() {
+        assert_eq!(
+            act_act_isda(date(2020, 12, 30), date(2021, 1, 1)),
+            Some(dec!(2) / dec!(366))
+        );
+        assert_eq!(
+            act_act_isda(date(2020, 12, 31), date(2021, 1, 1)),
+            Some(dec!(1) / dec!(366))
+        );
+        assert_eq!(
+            act_act_isda(date(2020, 12, 30), date(2021, 1, 2)),
+            Some(dec!(2) / dec!(366) + dec!(1) / dec!(365))
+        );
+        assert_eq!(
+            act_act_isda(date(2019, 12, 31), date(2020, 1, 1)),
+            Some(dec!(1) / dec!(365))
+        );
+    }
+
+    #[test]
+    fn act_act_isda_whole_years() {
+        assert_eq!(
+            act_act_isda(date(2020, 1, 1), date(2023, 1, 1)),
+            Some(dec!(3))
+        );
+        assert_eq!(
+            act_act_isda(date(2020, 1, 1), date(2021, 1, 1)),
+            Some(dec!(1))
+        );
+        assert_eq!(
+            act_act_isda(date(2021, 1, 1), date(2022, 1, 1)),
+            Some(dec!(1))
+        );
+    }
+
+    #[test]
+    fn act_act_isda_partial_year_denominators() {
+        // 182 days of the leap year 2020.
+        assert_eq!(
+            act_act_isda(date(2020, 1, 1), date(2020, 7, 1)),
+            Some(dec!(182) / dec!(366))
+        );
+        // 181 days of 2021.
+        assert_eq!(
+            act_act_isda(date(2021, 1, 1), date(2021, 7, 1)),
+            Some(dec!(181) / dec!(365))
+        );
+        // 184 days of 2020, two full years and 181 days of 2023.
+        assert_eq!(
+            act_act_isda(date(2020, 7, 1), date(2023, 7, 1)),
+            Some(dec!(184) / dec!(366) + dec!(2) + dec!(181) / dec!(365))
+        );
+    }
+
+    #[test]
+    fn inclusive_end_counts_one_more_day() {
+        let start = date(2024, 1, 1);
+        let end = date(2024, 1, 31);
+
+        for (convention, denominator) in [
+            (DayCountConvention::Actual360, dec!(360)),
+            (DayCountConvention::Actual365Fixed, dec!(365)),
+            (DayCountConvention::ActualActualIsda, dec!(366)),
+        ] {
+            assert_eq!(
+                convention.year_fraction_with_end(start, end, EndInclusivity::Exclusive),
+                Some(dec!(30) / denominator),
+                "{convention:?}"
+            );
+            assert_eq!(
+                convention.year_fraction_with_end(start, end, EndInclusivity::Inclusive),
+                Some(dec!(31) / denominator),
+                "{convention:?}"
+            );
+        }
+
+        assert_eq!(
+            DayCountConvention::Actual360.year_fraction_with_end(
+                start,
+                end,
+                EndInclusivity::default()
+            ),
+            DayCountConvention::Actual360.year_fraction(start, end)
+        );
+    }
+
+    #[test]
+    fn inclusive_end_is_ignored_by_thirty_360() {
+        let start = date(2024, 1, 1);
+        let end = date(2024, 1, 31);
+
+        assert_eq!(
+            DayCountConvention::Thirty360.year_fraction_with_end(
+                start,
+                end,
+                EndInclusivity::Inclusive
+            ),
+            DayCountConvention::Thirty360.year_fraction(start, end)
+        );
+        assert_eq!(
+            DayCountConvention::Actual360.year_fraction_with_end(
+                end,
+                start,
+                EndInclusivity::Inclusive
+            ),
+            None
+        );
+    }
+
+    /// The ACT/ACT (ISDA) fraction with the whole years added one at a time.
+    fn looped_act_act_isda(start: NaiveDate, end: NaiveDate) -> Decimal {
+        let year_length = |year| if is_leap(year) { 366 } else { 365 };
+
+        let first = year_length(start.year()) + 1 - i64::from(start.ordinal());
+        let mut dcf = Decimal::from(first) / Decimal::from(year_length(start.year()));
+        let mut year = start.year() + 1;
+        while year < end.year() {
+            dcf += Decimal::ONE;
+            year += 1;
+        }
+
+        let last = i64::from(end.ordinal()) - 1;
+        dcf + Decimal::from(last) / Decimal::from(year_length(end.year()))
+    }
+
+    #[test]
+    fn act_act_isda_whole_years_match_loop() {
+        for start in [date(2020, 7, 1), date(2021, 3, 15), date(1999, 12, 31)] {
+            for years in [1, 2, 10, 100] {
+                let end = date(start.year() + years, 5, 17);
+
+                assert_eq!(
+                    act_act_isda(start, end),
+                    Some(looped_act_act_isda(start, end)),
+                    "{start} {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn d30_360_adjacent_month_31st_to_1st() {
+        for (start, end) in [
+            (date(2023, 1, 31), date(2023, 2, 1)),
+            (date(2023, 3, 31), date(2023, 4, 1)),
+            (date(2023, 12, 31), date(2024, 1, 1)),
+        ] {
+            let expected = dec!(1) / dec!(360);
+
+            assert_eq!(d30_360(start, end), Some(expected), "{start} {end}");
+            assert_eq!(
+                d30_360_period(Period::new(start, end).unwrap()),
+                expected,
+                "{start} {end}"
+            );
+        }
+    }
+
+    #[test]
+    fn d30_360_near_boundaries() {
+        assert_eq!(d30_360(date(2023, 3, 31), date(2023, 2, 28)), None);
+        // The 31st counts as the 30th when the period starts on the 30th or the 31st.
+        assert_eq!(d30_360(date(2023, 3, 30), date(2023, 3, 31)), Some(dec!(0)));
+        assert_eq!(
+            d30_360(date(2023, 3, 29), date(2023, 3, 31)),
+            Some(dec!(2) / dec!(360))
+        );
+        assert_eq!(
+            d30_360_period(Period::new(date(2023, 3, 31), date(2023, 3, 31)).unwrap()),
+            dec!(0)
+        );
+    }
+
+    #[test]
+    fn act_360_checked_near_precision_limit() {
+        // 191 491 528 days, i.e. about 531 921 years, have 23 decimal places left.
+        assert_eq!(
+            act_360_checked(NaiveDate::MIN, NaiveDate::MAX, 23),
+            Ok(dec!(531920.91111111111111111111111))
+        );
+        assert_eq!(
+            act_360_checked(NaiveDate::MIN, NaiveDate::MAX, 24),
+            Err(DayCountError::PrecisionLoss { decimal_places: 23 })
+        );
+        assert_eq!(
+            act_360_checked(date(2024, 1, 1), date(2024, 1, 2), 28),
+            Ok(dec!(0.0027777777777777777777777778))
+        );
+        assert_eq!(
+            act_360_checked(date(2024, 1, 1), date(2024, 1, 2), 29),
+            Err(DayCountError::PrecisionLoss { decimal_places: 28 })
+        );
+    }
+
+    #[test]
+    fn act_360_checked_exact_fractions() {
+        // 730 485 days are a multiple of 9, so the fraction is exact with 3 decimal places.
+        assert_eq!(
+            act_360_checked(date(2024, 1, 1), date(4024, 1, 1), 28),
+            Ok(dec!(2029.125))
+        );
+        assert_eq!(
+            act_360_checked(date(2024, 1, 2), date(2024, 1, 1), 0),
+            Err(DayCountError::StartAfterEnd)
+        );
+    }
+
+    #[test]
+    fn d30_365_full_year() {
+        let (start, end) = (date(2023, 3, 15), date(2024, 3, 15));
+
+        assert_eq!(d30_360(start, end), Some(dec!(1)));
+        assert_eq!(d30_365(start, end), Some(dec!(360) / dec!(365)));
+        assert_eq!(
+            DayCountConvention::Thirty365.year_fraction(start, end),
+            Some(dec!(360) / dec!(365))
+        );
+        // The 31st counts as the 30th, as in 30/360.
+        assert_eq!(
+            d30_365(date(2023, 1, 31), date(2023, 3, 31)),
+            Some(dec!(60) / dec!(365))
+        );
+        assert_eq!(d30_365(end, start), None);
+    }
+
+    #[test]
+    fn year_fraction_str_act_act_isda_golden() {
+        let (start, end) = (date(2003, 11, 1), date(2004, 5, 1));
+        let convention = DayCountConvention::ActualActualIsda;
+
+        let fraction = year_fraction_str(start, end, &convention).unwrap();
+        assert_eq!(fraction, "0.497724380567");
+        assert_eq!(
+            parse_year_fraction(&fraction),
+            convention.year_fraction_rounded(
+                start,
+                end,
+                YEAR_FRACTION_STR_DP,
+                RoundingStrategy::MidpointNearestEven
+            )
+        );
+    }
+
+    #[test]
+    fn year_fraction_str_pads_to_fixed_precision() {
+        let fraction = year_fraction_str(
+            date(2023, 1, 1),
+            date(2024, 1, 1),
+            &DayCountConvention::ActualActualIsda,
+        );
+        assert_eq!(fraction.as_deref(), Some("1.000000000000"));
+        assert_eq!(parse_year_fraction("1.000000000000"), Some(dec!(1)));
+    }
+
+    #[test]
+    fn year_fraction_str_rejects_reversed_dates() {
+        let convention = DayCountConvention::ActualActualIsda;
+        assert_eq!(
+            year_fraction_str(date(2004, 5, 1), date(2003, 11, 1), &convention),
+            None
+        );
+        assert_eq!(parse_year_fraction("not a fraction"), None);
+    }
+
+    #[test]
+    fn d30_360_german_february_month_end() {
+        let thirty_days = dec!(30) / THREE_SIXTY;
+        assert_eq!(
+            d30_360_german(date(2023, 2, 28), date(2023, 3, 31)),
+            Some(thirty_days)
+        );
+        assert_eq!(
+            d30e_360(date(2023, 2, 28), date(2023, 3, 31)),
+            Some(dec!(32) / THREE_SIXTY)
+        );
+        assert_eq!(
+            d30_360_german(date(2024, 1, 31), date(2024, 2, 29)),
+            Some(thirty_days)
+        );
+        assert_eq!(
+            d30e_360(date(2024, 1, 31), date(2024, 2, 29)),
+            Some(dec!(29) / THREE_SIXTY)
+        );
+        assert_eq!(
+            d30_360_german(date(2023, 8, 31), date(2024, 2, 29)),
+            Some(dec!(0.5))
+        );
+    }
+
+    #[test]
+    fn d30_360_german_february_28th_in_leap_year() {
+        // February 28th, 2024 is not the last day of February, so it is not moved.
+        assert_eq!(
+            d30_360_german(date(2024, 2, 28), date(2024, 3, 31)),
+            Some(dec!(32) / THREE_SIXTY)
+        );
+        assert_eq!(d30_360_german(date(2024, 3, 31), date(2024, 2, 29)), None);
+        assert_eq!(
+            DayCountConvention::Thirty360German.year_fraction(date(2023, 2, 28), date(2023, 3, 31)),
+            d30_360_german(date(2023, 2, 28), date(2023, 3, 31))
+        );
+    }
+
+    #[test]
+    fn year_fraction_f64_within_epsilon_of_decimal() {
+        let conventions = [
+            DayCountConvention::Actual360,
+            DayCountConvention::Actual365Fixed,
+            DayCountConvention::Actual365_25,
+            DayCountConvention::ActualActualIsda,
+            DayCountConvention::Thirty360,
+            DayCountConvention::ThirtyE360,
+            DayCountConvention::Thirty360German,
+            DayCountConvention::Thirty365,
+        ];
+        let periods = [
+            (date(2003, 11, 1), date(2004, 5, 1)),
+            (date(2024, 1, 31), date(2024, 2, 29)),
+            (date(2020, 3, 15), date(2050, 9, 17)),
+        ];
+
+        for convention in &conventions {
+            for (start, end) in periods {
+                let fraction = convention.year_fraction(start, end).unwrap();
+                let approximation = convention.year_fraction_f64(start, end).unwrap();
+                let error = (Decimal::try_from(approximation).unwrap() - fraction).abs();
+                assert!(error < dec!(1e-14), "{convention:?} {start} {end}");
+            }
+        }
+    }
+
+    #[test]
+    fn year_fraction_f64_none_like_decimal() {
+        let (start, end) = (date(2024, 1, 1), date(2024, 7, 1));
+        assert_eq!(
+            DayCountConvention::Actual360.year_fraction_f64(end, start),
+            None
+        );
+        assert_eq!(
+            DayCountConvention::ActualActualIcma.year_fraction_f64(start, end),
+            None
+        );
+        assert_eq!(
+            DayCountConvention::Business252.year_fraction_f64(start, end),
+            None
+        );
+        // The result is converted from the rounded decimal fraction, so it may differ from
+        // `182.0 / 360.0` in the last place.
+        let approximation = DayCountConvention::Actual360
+            .year_fraction_f64(start, end)
+            .unwrap();
+        assert!((approximation - 182.0 / 360.0).abs() <= f64::EPSILON);
+    }
+
+    #[test]
+    fn act_act_isda_same_leap_year_denominator_is_366() {
+        // A full leap year ends on January 1st of the next year and is exactly one, rather
+        // than the 365 / 366 = 0.99726... of a period ending on December 31st.
+        assert_eq!(
+            act_act_isda(date(2020, 1, 1), date(2021, 1, 1)),
+            Some(dec!(1))
+        );
+        assert_eq!(
+            act_act_isda(date(2020, 1, 1), date(2020, 12, 31)),
+            Some(dec!(365) / dec!(366))
+        );
+        assert_eq!(
+            act_act_isda(date(2020, 2, 28), date(2020, 3, 1)),
+            Some(dec!(2) / dec!(366))
+        );
+        assert_eq!(
+            act_act_isda(date(2020, 1, 1), date(2020, 1, 2)),
+            Some(dec!(1) / dec!(366))
+        );
+    }
+
+    #[test]
+    fn act_act_isda_documented_example() {
+        let fraction = act_act_isda(date(2003, 11, 1), date(2004, 5, 1)).unwrap();
+        assert_eq!(fraction, dec!(61) / dec!(365) + dec!(121) / dec!(366));
+        assert_eq!(fraction.round_dp(12), dec!(0.497724380567));
+    }
+}
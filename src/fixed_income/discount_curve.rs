@@ -0,0 +1,195 @@
+//! This module provides discount curves for valuing cashflows at non-flat rates.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::fixed_income::day_count_fraction::DayCountConvention;
+
+/// A `DiscountCurve` represents discount factors given at pillar dates, interpolated linearly
+/// on the year fractions from the reference date of the curve.
+///
+/// The discount factor on the reference date is one. The curve is not extrapolated: dates
+/// before the reference date or after the last pillar have no discount factor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscountCurve {
+    reference: NaiveDate,
+    day_count: DayCountConvention,
+    pillars: Vec<(Decimal, Decimal)>,
+    last: NaiveDate,
+}
+
+impl DiscountCurve {
+    /// Creates a curve from the reference date and the discount factors at the pillar dates,
+    /// measuring the year fractions using the `day_count` convention supplied.
+    ///
+    /// Returns `None` if there are no pillars, a pillar is not after the reference date, two
+    /// pillars share a date, a discount factor is not positive, or the year fractions cannot be
+    /// calculated with the `day_count` convention (e.g. [`DayCountConvention::Business252`]).
+    #[must_use]
+    pub fn new(
+        reference: NaiveDate,
+        mut pillars: Vec<(NaiveDate, Decimal)>,
+        day_count: DayCountConvention,
+    ) -> Option<Self> {
+        pillars.sort_unstable_by_key(|(date, _)| *date);
+        let last = pillars.last()?.0;
+        if pillars[0].0 <= reference
+            || pillars.windows(2).any(|pair| pair[0].0 == pair[1].0)
+            || pillars.iter().any(|(_, factor)| *factor <= Decimal::ZERO)
+        {
+            return None;
+        }
+
+        let mut times = vec![(Decimal::ZERO, Decimal::ONE)];
+        for (date, factor) in pillars {
+            times.push((day_count.year_fraction(reference, date)?, factor));
+        }
+
+        Some(DiscountCurve {
+            reference,
+            day_count,
+            pillars: times,
+            last,
+        })
+    }
+
+    /// Returns the reference date of the curve.
+    #[must_use]
+    pub fn reference(&self) -> NaiveDate {
+        self.reference
+    }
+
+    /// Returns the day count convention of the curve.
+    #[must_use]
+    pub fn day_count(&self) -> DayCountConvention {
+        self.day_count
+    }
+
+    /// Returns the discount factor on the date, interpolated linearly between the surrounding
+    /// pillars.
+    ///
+    /// Returns `None` if the date is before the reference date or after the last pillar.
+    #[must_use]
+    pub fn discount_factor(&self, date: NaiveDate) -> Option<Decimal> {
+        if date < self.reference || date > self.last {
+            return None;
+        }
+
+        let time = self.day_count.year_fraction(self.reference, date)?;
+        let index = self.pillars.partition_point(|(pillar, _)| *pillar < time);
+        let (end_time, end_factor) = *self.pillars.get(index)?;
+        if index == 0 || end_time == time {
+            return Some(end_factor);
+        }
+
+        let (start_time, start_factor) = self.pillars[index - 1];
+        let weight = (time - start_time).checked_div(end_time - start_time)?;
+        Some(start_factor + (end_factor - start_factor) * weight)
+    }
+
+    /// Returns the simply compounded forward rate implied by the curve from `start` to `end`,
+    /// i.e. `(df(start) / df(end) - 1) / dcf`, where `dcf` is the day count fraction of the
+    /// period using the `day_count` convention supplied.
+    ///
+    /// Returns `None` if either date is outside the curve, or the day count fraction cannot be
+    /// calculated or is zero.
+    #[must_use]
+    pub fn forward_rate(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+        day_count: &DayCountConvention,
+    ) -> Option<Decimal> {
+        let growth = self
+            .discount_factor(start)?
+            .checked_div(self.discount_factor(end)?)?;
+
+        (growth - Decimal::ONE).checked_div(day_count.year_fraction(start, end)?)
+    }
+
+    /// Returns the present value of the cashflows, discounted with the curve.
+    ///
+    /// Returns `None` if any cashflow is outside the curve.
+    #[must_use]
+    pub fn present_value(&self, cashflows: &[(NaiveDate, Decimal)]) -> Option<Decimal> {
+        cashflows
+            .iter()
+            .try_fold(Decimal::ZERO, |value, (date, amount)| {
+                Some(value + *amount * self.discount_factor(*date)?)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A curve with pillars 90 and 180 days (`0.25` and `0.5` years) after the reference date.
+    fn curve() -> DiscountCurve {
+        DiscountCurve::new(
+            date(2024, 1, 1),
+            vec![
+                (date(2024, 6, 29), dec!(0.98)),
+                (date(2024, 3, 31), dec!(0.99)),
+            ],
+            DayCountConvention::Actual360,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn interpolates_between_pillars() {
+        let curve = curve();
+
+        assert_eq!(curve.discount_factor(date(2024, 1, 1)), Some(dec!(1)));
+        assert_eq!(curve.discount_factor(date(2024, 3, 31)), Some(dec!(0.99)));
+        assert_eq!(curve.discount_factor(date(2024, 6, 29)), Some(dec!(0.98)));
+
+        // Halfway between the pillars, 135 days after the reference date.
+        assert_eq!(curve.discount_factor(date(2024, 5, 15)), Some(dec!(0.985)));
+        // Halfway between the reference date and the first pillar.
+        assert_eq!(curve.discount_factor(date(2024, 2, 15)), Some(dec!(0.995)));
+    }
+
+    #[test]
+    fn does_not_extrapolate() {
+        let curve = curve();
+
+        assert_eq!(curve.discount_factor(date(2023, 12, 31)), None);
+        assert_eq!(curve.discount_factor(date(2024, 6, 30)), None);
+        assert_eq!(
+            curve.present_value(&[
+                (date(2024, 3, 31), dec!(100)),
+                (date(2025, 1, 1), dec!(100))
+            ]),
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_pillars() {
+        let reference = date(2024, 1, 1);
+        let day_count = DayCountConvention::Actual360;
+
+        assert!(DiscountCurve::new(reference, vec![], day_count).is_none());
+        assert!(DiscountCurve::new(reference, vec![(reference, dec!(1))], day_count).is_none());
+        assert!(DiscountCurve::new(
+            reference,
+            vec![
+                (date(2024, 3, 31), dec!(0.99)),
+                (date(2024, 3, 31), dec!(0.98))
+            ],
+            day_count
+        )
+        .is_none());
+        assert!(
+            DiscountCurve::new(reference, vec![(date(2024, 3, 31), dec!(0))], day_count).is_none()
+        );
+    }
+}
@@ -0,0 +1,146 @@
+//! This module provides tools for the calculation of floating rate coupons.
+
+use rust_decimal::Decimal;
+
+/// Interpolates linearly between the rates of two index tenors to the length of a period, e.g.
+/// the rate of a two month front stub period between the `1M` and `3M` fixings, as in the
+/// `ISDA` definitions of linear interpolation.
+///
+/// The tenors and the period are given as their numbers of days. A period outside the tenors
+/// is extrapolated along the same line, and the short rate is returned if both tenors have the
+/// same length.
+#[must_use]
+pub fn interpolate_rate(
+    short_tenor_days: i64,
+    short_rate: Decimal,
+    long_tenor_days: i64,
+    long_rate: Decimal,
+    period_days: i64,
+) -> Decimal {
+    if short_tenor_days == long_tenor_days {
+        return short_rate;
+    }
+
+    let short = Decimal::from(short_tenor_days);
+    let weight = (Decimal::from(period_days) - short) / (Decimal::from(long_tenor_days) - short);
+
+    short_rate + (long_rate - short_rate) * weight
+}
+
+/// Method of combining the daily fixings of an overnight risk free rate (e.g. `SOFR`, `€STR`)
+/// into the rate of an interest period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CompoundingMethod {
+    /// Compounded in arrears, the daily interest is reinvested.
+    #[default]
+    Compounded,
+    /// Simple average of the daily fixings weighted by their year fractions.
+    SimpleAverage,
+}
+
+/// Returns the annualized rate of an interest period from the overnight `fixings`, given as
+/// pairs of the fixed rate and the day count fraction of the interval it applies to.
+///
+/// [`CompoundingMethod::Compounded`] returns `(product of (1 + rate * dcf) - 1) / sum of dcf`,
+/// while [`CompoundingMethod::SimpleAverage`] returns `sum of (rate * dcf) / sum of dcf`.
+///
+/// Returns `None` if there are no fixings, the total year fraction is zero or the calculation
+/// overflows.
+#[must_use]
+pub fn rfr_rate(fixings: &[(Decimal, Decimal)], method: CompoundingMethod) -> Option<Decimal> {
+    let mut total = Decimal::ZERO;
+    let mut accrued = match method {
+        CompoundingMethod::Compounded => Decimal::ONE,
+        CompoundingMethod::SimpleAverage => Decimal::ZERO,
+    };
+
+    for &(rate, dcf) in fixings {
+        let interest = rate.checked_mul(dcf)?;
+        accrued = match method {
+            CompoundingMethod::Compounded => accrued.checked_mul(Decimal::ONE + interest)?,
+            CompoundingMethod::SimpleAverage => accrued.checked_add(interest)?,
+        };
+        total = total.checked_add(dcf)?;
+    }
+
+    if method == CompoundingMethod::Compounded {
+        accrued -= Decimal::ONE;
+    }
+
+    accrued.checked_div(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+
+    #[test]
+    fn two_month_rate_between_1m_and_3m() {
+        assert_eq!(
+            interpolate_rate(30, dec!(0.030), 90, dec!(0.036), 60),
+            dec!(0.033)
+        );
+
+        // January 15th, 2024: the 1M and 3M tenors are 31 and 91 days, the stub 60 days.
+        let rate = interpolate_rate(31, dec!(0.0385), 91, dec!(0.0391), 60);
+        assert_eq!(rate, dec!(0.0385) + dec!(0.0006) * dec!(29) / dec!(60));
+        assert!(rate > dec!(0.0385) && rate < dec!(0.0391));
+    }
+
+    #[test]
+    fn interpolate_rate_at_and_beyond_tenors() {
+        assert_eq!(
+            interpolate_rate(30, dec!(0.030), 90, dec!(0.036), 30),
+            dec!(0.030)
+        );
+        assert_eq!(
+            interpolate_rate(30, dec!(0.030), 90, dec!(0.036), 90),
+            dec!(0.036)
+        );
+        assert_eq!(
+            interpolate_rate(30, dec!(0.030), 90, dec!(0.036), 120),
+            dec!(0.039)
+        );
+        assert_eq!(
+            interpolate_rate(90, dec!(0.030), 90, dec!(0.036), 60),
+            dec!(0.030)
+        );
+    }
+
+    #[test]
+    fn compounded_and_simple_average_on_same_fixings() {
+        let fixings = [(dec!(0.10), dec!(0.5)), (dec!(0.10), dec!(0.5))];
+
+        assert_eq!(
+            rfr_rate(&fixings, CompoundingMethod::Compounded),
+            Some(dec!(0.1025))
+        );
+        assert_eq!(
+            rfr_rate(&fixings, CompoundingMethod::SimpleAverage),
+            Some(dec!(0.10))
+        );
+    }
+
+    #[test]
+    fn overnight_week_with_weekend_fixing() {
+        // Monday to Friday fixings, the Friday one applying over the weekend.
+        let fixings = [
+            (dec!(0.0531), dec!(1) / dec!(360)),
+            (dec!(0.0532), dec!(1) / dec!(360)),
+            (dec!(0.0530), dec!(1) / dec!(360)),
+            (dec!(0.0531), dec!(1) / dec!(360)),
+            (dec!(0.0533), dec!(3) / dec!(360)),
+        ];
+
+        let simple = rfr_rate(&fixings, CompoundingMethod::SimpleAverage).unwrap();
+        let compounded = rfr_rate(&fixings, CompoundingMethod::Compounded).unwrap();
+
+        // The simple average is weighted by the year fractions: 0.3723 / 7.
+        assert_eq!(simple.round_dp(20), (dec!(0.3723) / dec!(7)).round_dp(20));
+        assert!(compounded > simple);
+        assert!(compounded - simple < dec!(0.0001));
+        assert_eq!(rfr_rate(&[], CompoundingMethod::Compounded), None);
+    }
+}
@@ -0,0 +1,255 @@
+//! This module provides the floating rate note type.
+
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::{
+    calendar::Business,
+    fixed_income::{
+        calculation_schedule::Schedule, day_count_fraction::DayCountConvention,
+        discount_curve::DiscountCurve,
+    },
+};
+
+/// A `FixingSource` holds the published fixings of a floating rate index, and the number of
+/// business days before the start of an accrual period on which the index fixes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixingSource {
+    fixing_lag: i64,
+    fixings: BTreeMap<NaiveDate, Decimal>,
+}
+
+impl FixingSource {
+    /// Creates a source with no fixings, fixing `fixing_lag` business days before the start
+    /// of each accrual period (e.g. `2` for `EURIBOR`).
+    #[must_use]
+    pub fn new(fixing_lag: i64) -> Self {
+        FixingSource {
+            fixing_lag,
+            fixings: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the `rate` fixed on the `date`, replacing any fixing on that date.
+    #[must_use]
+    pub fn fixing(mut self, date: NaiveDate, rate: Decimal) -> Self {
+        self.fixings.insert(date, rate);
+        self
+    }
+
+    /// Returns the number of business days between the fixing date and the accrual start.
+    #[must_use]
+    pub fn fixing_lag(&self) -> i64 {
+        self.fixing_lag
+    }
+
+    /// Returns the rate fixed on the date, if it has been published.
+    #[must_use]
+    pub fn rate_on(&self, date: NaiveDate) -> Option<Decimal> {
+        self.fixings.get(&date).copied()
+    }
+}
+
+/// A `FloatingRateNote` represents a note paying a floating index rate plus a spread on its
+/// notional over the periods of a schedule.
+#[derive(Debug, Clone)]
+pub struct FloatingRateNote {
+    schedule: Schedule,
+    day_count: DayCountConvention,
+    spread: Decimal,
+    notional: Decimal,
+    fixings: FixingSource,
+    curve: Option<DiscountCurve>,
+}
+
+impl FloatingRateNote {
+    /// Creates a note paying the index fixed by the `fixings` source plus the `spread` (e.g.
+    /// `0.005` for 50bp) on the `notional` over the periods of the `schedule`, using the
+    /// `day_count` convention supplied.
+    #[must_use]
+    pub fn new(
+        schedule: Schedule,
+        day_count: DayCountConvention,
+        spread: Decimal,
+        notional: Decimal,
+        fixings: FixingSource,
+    ) -> Self {
+        FloatingRateNote {
+            schedule,
+            day_count,
+            spread,
+            notional,
+            fixings,
+            curve: None,
+        }
+    }
+
+    /// Sets the curve whose forward rates project the index of the periods which have not
+    /// fixed yet.
+    #[must_use]
+    pub fn curve(mut self, curve: DiscountCurve) -> Self {
+        self.curve = Some(curve);
+        self
+    }
+
+    /// Returns the schedule.
+    #[must_use]
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns the spread over the index.
+    #[must_use]
+    pub fn spread(&self) -> Decimal {
+        self.spread
+    }
+
+    /// Returns the notional.
+    #[must_use]
+    pub fn notional(&self) -> Decimal {
+        self.notional
+    }
+
+    /// Returns the coupon of every period of the schedule, in chronological order, as pairs of
+    /// the payment date and the amount `notional * (index + spread) * dcf`. The notional
+    /// itself is not included.
+    ///
+    /// The index of a period is its fixing on the fixing date, or, if it has not been
+    /// published, the forward rate of the period from the [`curve`] (see
+    /// [`DiscountCurve::forward_rate`]).
+    ///
+    /// Returns `None` if the index of a period is neither fixed nor projected by a curve, or a
+    /// day count fraction cannot be calculated with the convention.
+    ///
+    /// [`curve`]: FloatingRateNote::curve
+    #[must_use]
+    pub fn cashflows(&self, calendar: &dyn Business) -> Option<Vec<(NaiveDate, Decimal)>> {
+        self.schedule
+            .periods()
+            .iter()
+            .map(|period| {
+                let (start, end) = (period.accrual_start(), period.accrual_end());
+                let fixing_date = calendar.fixing_date(start, self.fixings.fixing_lag())?;
+                let index = match self.fixings.rate_on(fixing_date) {
+                    Some(rate) => rate,
+                    None => self
+                        .curve
+                        .as_ref()?
+                        .forward_rate(start, end, &self.day_count)?,
+                };
+                let dcf = calendar.period_year_fraction(start, end, &self.day_count)?;
+
+                Some((
+                    period.payment_date(),
+                    self.notional * (index + self.spread) * dcf,
+                ))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal_macros::dec;
+
+    use super::*;
+    use crate::{
+        calendar::target::TargetCalendar,
+        fixed_income::calculation_schedule::{Frequency, ScheduleBuilder},
+        BusinessDayConvetion,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    /// A quarterly 1,000,000 `ACT/360` note paying the index plus 50bp from January 15th, 2024
+    /// to January 15th, 2025, with the index fixed at a flat 4% for the first `fixed` periods.
+    fn quarterly(fixed: usize) -> FloatingRateNote {
+        let schedule =
+            ScheduleBuilder::new(date(2024, 1, 15), date(2025, 1, 15), Frequency::Quarterly)
+                .convention(BusinessDayConvetion::NoAdjustment)
+                .build(&TargetCalendar)
+                .unwrap();
+        let fixings =
+            schedule
+                .periods()
+                .iter()
+                .take(fixed)
+                .fold(FixingSource::new(2), |fixings, period| {
+                    let fixing_date = TargetCalendar
+                        .fixing_date(period.accrual_start(), 2)
+                        .unwrap();
+                    fixings.fixing(fixing_date, dec!(0.04))
+                });
+
+        FloatingRateNote::new(
+            schedule,
+            DayCountConvention::Actual360,
+            dec!(0.005),
+            dec!(1000000),
+            fixings,
+        )
+    }
+
+    #[test]
+    fn quarterly_flat_index_plus_spread() {
+        let cashflows = quarterly(4).cashflows(&TargetCalendar).unwrap();
+        let rounded: Vec<_> = cashflows
+            .iter()
+            .map(|(payment, amount)| (*payment, amount.round_dp(10)))
+            .collect();
+
+        // 1,000,000 * 4.5% * 91 / 360 = 11,375 and * 92 / 360 = 11,500.
+        assert_eq!(
+            rounded,
+            vec![
+                (date(2024, 4, 15), dec!(11375)),
+                (date(2024, 7, 15), dec!(11375)),
+                (date(2024, 10, 15), dec!(11500)),
+                (date(2025, 1, 15), dec!(11500)),
+            ]
+        );
+    }
+
+    #[test]
+    fn fixes_two_business_days_before_accrual_start() {
+        // Monday, January 15th, 2024 fixes on Thursday, January 11th.
+        let fixings = &quarterly(1).fixings;
+        assert_eq!(fixings.fixing_lag(), 2);
+        assert_eq!(fixings.rate_on(date(2024, 1, 11)), Some(dec!(0.04)));
+        assert_eq!(fixings.rate_on(date(2024, 1, 15)), None);
+    }
+
+    #[test]
+    fn unfixed_period_without_curve() {
+        assert_eq!(quarterly(3).cashflows(&TargetCalendar), None);
+    }
+
+    #[test]
+    fn unfixed_period_projected_by_curve() {
+        // A curve with a flat 4% forward over the last period, October 15th, 2024 to January
+        // 15th, 2025.
+        let factor = Decimal::ONE / (Decimal::ONE + dec!(0.04) * dec!(92) / dec!(360));
+        let curve = DiscountCurve::new(
+            date(2024, 10, 15),
+            vec![(date(2025, 1, 15), factor)],
+            DayCountConvention::Actual360,
+        )
+        .unwrap();
+
+        let cashflows = quarterly(3)
+            .curve(curve)
+            .cashflows(&TargetCalendar)
+            .unwrap();
+        assert_eq!(cashflows.len(), 4);
+        assert_eq!(
+            cashflows[2],
+            quarterly(4).cashflows(&TargetCalendar).unwrap()[2]
+        );
+        assert_eq!(cashflows[3].0, date(2025, 1, 15));
+        assert!((cashflows[3].1 - dec!(11500)).abs() < dec!(1e-18));
+    }
+}
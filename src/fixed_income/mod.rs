@@ -1,4 +1,14 @@
 //! The modules provides tools for a fixed income calculation.
 
+pub mod amortization;
+pub mod bond;
+pub mod calculation_schedule;
+pub mod cashflow;
+pub mod compounding;
+pub mod coupon;
+pub mod coupon_rate;
 pub mod day_count_fraction;
-pub mod calculation_schedule;
\ No newline at end of file
+pub mod discount_curve;
+pub mod floating_rate;
+pub mod floating_rate_note;
+pub mod period;
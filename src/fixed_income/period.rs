@@ -0,0 +1,103 @@
+//! This module provides the [`Period`] type representing an ordered range of dates.
+
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+
+use crate::fixed_income::day_count_fraction::DayCountConvention;
+
+/// A `Period` is a range of dates from `start` to `end`, where `start` is guaranteed not to
+/// be after `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Period {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl Period {
+    /// Creates a new period and returns it in a form of `Option<Period>` enum.
+    ///
+    /// Returns `None` if `start` is after `end`.
+    #[must_use]
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Option<Period> {
+        if start > end {
+            return None;
+        }
+
+        Some(Period { start, end })
+    }
+
+    /// Returns the first day of the period.
+    #[must_use]
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    /// Returns the last day of the period.
+    #[must_use]
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// Returns the number of days between the start and the end of the period.
+    #[must_use]
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days()
+    }
+
+    /// Returns the day count fraction of the period using the convention supplied.
+    #[must_use]
+    pub fn day_count(&self, convention: &DayCountConvention) -> Option<Decimal> {
+        convention.year_fraction(self.start, self.end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::fixed_income::day_count_fraction::{act_360, act_365f, act_act_isda, d30_360};
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn new_rejects_reversed_dates() {
+        assert_eq!(Period::new(date(2024, 1, 2), date(2024, 1, 1)), None);
+    }
+
+    #[test]
+    fn new_accepts_ordered_dates() {
+        let period = Period::new(date(2024, 1, 1), date(2024, 3, 1)).unwrap();
+        assert_eq!(period.start(), date(2024, 1, 1));
+        assert_eq!(period.end(), date(2024, 3, 1));
+        assert_eq!(period.days(), 60);
+
+        let empty = Period::new(date(2024, 1, 1), date(2024, 1, 1)).unwrap();
+        assert_eq!(empty.days(), 0);
+    }
+
+    #[test]
+    fn day_count_matches_free_functions() {
+        let (start, end) = (date(2023, 11, 15), date(2024, 5, 31));
+        let period = Period::new(start, end).unwrap();
+
+        assert_eq!(
+            period.day_count(&DayCountConvention::Actual360),
+            act_360(start, end)
+        );
+        assert_eq!(
+            period.day_count(&DayCountConvention::Actual365Fixed),
+            act_365f(start, end)
+        );
+        assert_eq!(
+            period.day_count(&DayCountConvention::ActualActualIsda),
+            act_act_isda(start, end)
+        );
+        assert_eq!(
+            period.day_count(&DayCountConvention::Thirty360),
+            d30_360(start, end)
+        );
+    }
+}
@@ -8,11 +8,17 @@
 //! conventions used.
 
 pub mod calendar;
+pub mod conventions;
 pub mod fixed_income;
+pub mod tenor;
+
+use std::{error::Error, fmt};
+
+use calendar::CalendarExhausted;
 
 /// A `BusinessDayConvetion` represents the method of date rolling in case
 /// it falls on a non-business day.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum BusinessDayConvetion {
     /// The adjusted date will be the first business day following the unadjusted date.
@@ -21,6 +27,9 @@ pub enum BusinessDayConvetion {
     /// unless it falls in the next calendar month - then the first preceding business day
     /// is the adjusted date.
     ModifiedFollowiing,
+    /// If the unadjusted date is the last calendar day of its month, the adjusted date will be
+    /// the last business day of that month. Otherwise the `ModifiedFollowiing` convention applies.
+    EndOfMonthModifiedFollowing,
     /// The adjusted date will be the first business day preceding the unadjusted date.
     Preceding,
     /// The adjusted date will be the first business day preceding the unadjusted date,
@@ -30,3 +39,34 @@ pub enum BusinessDayConvetion {
     /// No date adjustment is made.
     NoAdjustment,
 }
+
+/// An `AccrualError` represents a failure of a date or accrual calculation, returned by the
+/// `try_` variants of the calendar methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AccrualError {
+    /// No business day was found before the end of the supported date range.
+    CalendarExhausted(CalendarExhausted),
+}
+
+impl fmt::Display for AccrualError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccrualError::CalendarExhausted(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for AccrualError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AccrualError::CalendarExhausted(error) => Some(error),
+        }
+    }
+}
+
+impl From<CalendarExhausted> for AccrualError {
+    fn from(error: CalendarExhausted) -> Self {
+        AccrualError::CalendarExhausted(error)
+    }
+}
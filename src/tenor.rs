@@ -0,0 +1,225 @@
+//! The `tenor` module provides the [`Tenor`] type used for date arithmetic.
+
+use std::ops::Add;
+
+use chrono::{Datelike, Duration, NaiveDate};
+
+/// A `Tenor` represents a length of time, such as `3M` or `10Y`, by which
+/// dates are advanced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tenor {
+    /// A number of calendar days.
+    Days(i32),
+    /// A number of weeks.
+    Weeks(i32),
+    /// A number of calendar months.
+    Months(i32),
+    /// A number of years.
+    Years(i32),
+}
+
+impl Tenor {
+    /// Creates a tenor of a number of calendar days.
+    #[must_use]
+    pub const fn days(days: i32) -> Tenor {
+        Tenor::Days(days)
+    }
+
+    /// Creates a tenor of a number of weeks.
+    #[must_use]
+    pub const fn weeks(weeks: i32) -> Tenor {
+        Tenor::Weeks(weeks)
+    }
+
+    /// Creates a tenor of a number of calendar months.
+    #[must_use]
+    pub const fn months(months: i32) -> Tenor {
+        Tenor::Months(months)
+    }
+
+    /// Creates a tenor of a number of years.
+    #[must_use]
+    pub const fn years(years: i32) -> Tenor {
+        Tenor::Years(years)
+    }
+
+    /// Advances the date by the tenor and returns it in a form of `Option<NaiveDate>` enum.
+    ///
+    /// Month and year tenors keep the day of month, clamped to the length of the resulting
+    /// month (e.g. January 31st plus one month is February 28th or 29th). If `end_of_month`
+    /// is set and the date is the last day of its month, the last day of the resulting month
+    /// is returned instead.
+    ///
+    /// Returns `None` if the resulting date is out of range.
+    #[must_use]
+    pub fn advance(&self, day: NaiveDate, end_of_month: bool) -> Option<NaiveDate> {
+        match *self {
+            Tenor::Days(days) => day.checked_add_signed(Duration::days(i64::from(days))),
+            Tenor::Weeks(weeks) => day.checked_add_signed(Duration::weeks(i64::from(weeks))),
+            Tenor::Months(months) => add_months(day, months, end_of_month),
+            Tenor::Years(years) => add_months(day, years.checked_mul(12)?, end_of_month),
+        }
+    }
+}
+
+/// Returns the `count` dates advanced from the start date by one to `count` multiples of the
+/// `step`, e.g. the pillar dates of a strip of monthly `FRA`s.
+///
+/// Each date is advanced from the start date by a multiple of the step, rather than from the
+/// previous date, so the day of month is not lost after a short month (e.g. January 31st,
+/// 2024 is followed by February 29th and March 31st). The strip stops early if a date is out
+/// of range.
+#[must_use]
+pub fn tenor_strip(start: NaiveDate, step: &Tenor, count: usize) -> Vec<NaiveDate> {
+    (1..=count)
+        .map_while(|multiple| {
+            let multiple = i32::try_from(multiple).ok()?;
+            let tenor = match *step {
+                Tenor::Days(days) => Tenor::Days(days.checked_mul(multiple)?),
+                Tenor::Weeks(weeks) => Tenor::Weeks(weeks.checked_mul(multiple)?),
+                Tenor::Months(months) => Tenor::Months(months.checked_mul(multiple)?),
+                Tenor::Years(years) => Tenor::Years(years.checked_mul(multiple)?),
+            };
+            tenor.advance(start, false)
+        })
+        .collect()
+}
+
+impl Add<Tenor> for NaiveDate {
+    type Output = NaiveDate;
+
+    /// Advances the date by the tenor, clamping the day of month to the length of the
+    /// resulting month for month and year tenors (see [`Tenor::advance`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting date is out of range.
+    fn add(self, tenor: Tenor) -> NaiveDate {
+        tenor
+            .advance(self, false)
+            .expect("`NaiveDate + Tenor` overflowed")
+    }
+}
+
+/// Adds a number of months to the date, clamping the day to the length of the resulting month.
+/// If `end_of_month` is set and the date is the last day of its month, the last day of the
+/// resulting month is returned.
+pub(crate) fn add_months(day: NaiveDate, months: i32, end_of_month: bool) -> Option<NaiveDate> {
+    let total = day
+        .year()
+        .checked_mul(12)?
+        .checked_add(day.month0().try_into().ok()?)?;
+    let total = total.checked_add(months)?;
+    let year = total.div_euclid(12);
+    let month = u32::try_from(total.rem_euclid(12)).ok()? + 1;
+
+    let last = last_day_of_month(year, month)?;
+    if end_of_month && is_end_of_month(day) {
+        return Some(last);
+    }
+
+    Some(last.with_day(day.day()).unwrap_or(last))
+}
+
+/// Returns the last calendar day of the month.
+pub(crate) fn last_day_of_month(year: i32, month: u32) -> Option<NaiveDate> {
+    let (next_year, next_month) = if month == 12 {
+        (year.checked_add(1)?, 1)
+    } else {
+        (year, month + 1)
+    };
+
+    NaiveDate::from_ymd_opt(next_year, next_month, 1)?.pred_opt()
+}
+
+/// Checks whether the date is the last calendar day of its month.
+pub(crate) fn is_end_of_month(day: NaiveDate) -> bool {
+    day.succ_opt()
+        .is_none_or(|next| next.month() != day.month())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn add_operator() {
+        assert_eq!(date(2024, 3, 15) + Tenor::days(20), date(2024, 4, 4));
+        assert_eq!(date(2024, 3, 15) + Tenor::weeks(2), date(2024, 3, 29));
+        assert_eq!(date(2024, 3, 15) + Tenor::months(3), date(2024, 6, 15));
+        assert_eq!(date(2024, 3, 15) + Tenor::years(10), date(2034, 3, 15));
+        assert_eq!(date(2024, 3, 15) + Tenor::months(-3), date(2023, 12, 15));
+    }
+
+    #[test]
+    fn add_months_clamps_to_month_end() {
+        assert_eq!(date(2023, 1, 31) + Tenor::months(1), date(2023, 2, 28));
+        assert_eq!(date(2024, 1, 31) + Tenor::months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 2, 29) + Tenor::years(1), date(2025, 2, 28));
+        // The operator does not apply the end-of-month rule.
+        assert_eq!(date(2023, 2, 28) + Tenor::months(1), date(2023, 3, 28));
+    }
+
+    #[test]
+    fn advance_end_of_month_rule() {
+        assert_eq!(
+            Tenor::months(1).advance(date(2023, 2, 28), true),
+            Some(date(2023, 3, 31))
+        );
+        assert_eq!(
+            Tenor::months(1).advance(date(2023, 2, 27), true),
+            Some(date(2023, 3, 27))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`NaiveDate + Tenor` overflowed")]
+    fn add_operator_panics_out_of_range() {
+        let _ = NaiveDate::MAX + Tenor::days(1);
+    }
+
+    #[test]
+    fn tenor_strip_of_24_months() {
+        let start = date(2024, 1, 31);
+        let strip = tenor_strip(start, &Tenor::months(1), 24);
+
+        assert_eq!(strip.len(), 24);
+        assert_eq!(strip[0], date(2024, 2, 29));
+        assert_eq!(strip[1], date(2024, 3, 31));
+        assert_eq!(strip[2], date(2024, 4, 30));
+        assert_eq!(strip[12], date(2025, 2, 28));
+        assert_eq!(strip[23], date(2026, 1, 31));
+        for (multiple, day) in (1..).zip(&strip) {
+            assert_eq!(Some(*day), add_months(start, multiple, false), "{day}");
+        }
+    }
+
+    #[test]
+    fn tenor_strip_other_steps() {
+        let start = date(2024, 3, 15);
+        assert_eq!(
+            tenor_strip(start, &Tenor::weeks(2), 3),
+            vec![date(2024, 3, 29), date(2024, 4, 12), date(2024, 4, 26)]
+        );
+        assert_eq!(
+            tenor_strip(start, &Tenor::years(5), 2),
+            vec![date(2029, 3, 15), date(2034, 3, 15)]
+        );
+        assert!(tenor_strip(start, &Tenor::months(3), 0).is_empty());
+    }
+
+    #[test]
+    fn tenor_strip_stops_at_end_of_range() {
+        let start = NaiveDate::MAX - Duration::days(2);
+        assert_eq!(
+            tenor_strip(start, &Tenor::days(1), 5),
+            vec![NaiveDate::MAX - Duration::days(1), NaiveDate::MAX]
+        );
+    }
+}